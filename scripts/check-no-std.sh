@@ -0,0 +1,26 @@
+#!/bin/sh
+# Builds the crate without `std` for every installed bare-metal target.
+# Install one with e.g. `rustup target add thumbv7em-none-eabi`.
+set -eu
+
+cd "$(dirname "$0")/.."
+
+candidates="thumbv7em-none-eabi thumbv7em-none-eabihf thumbv6m-none-eabi"
+installed=$(rustup target list --installed)
+checked=0
+
+for target in $candidates; do
+    if ! printf '%s\n' "$installed" | grep -qx "$target"; then
+        echo "skipping $target (not installed)"
+        continue
+    fi
+    echo "checking $target"
+    cargo build --target "$target" --no-default-features
+    cargo build --target "$target" --no-default-features --features serde,num-traits
+    checked=$((checked + 1))
+done
+
+if [ "$checked" -eq 0 ]; then
+    echo "error: none of $candidates is installed" >&2
+    exit 1
+fi
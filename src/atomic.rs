@@ -0,0 +1,201 @@
+//! Lock-free atomic counters with saturating read-modify-write operations.
+//!
+//! The `core` atomics wrap on overflow. These types instead run a
+//! compare-and-swap loop so that `fetch_add`, `fetch_sub` and `fetch_mul`
+//! clamp at the bounds of the underlying integer.
+
+use crate::{Number, Saturate, SaturatingNumber};
+use core::{fmt, sync::atomic::Ordering};
+
+/// Returns the strongest ordering a failed compare-and-swap (which is just a
+/// load) may use for the given read-modify-write ordering.
+fn load_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Acquire,
+        order => order,
+    }
+}
+
+macro_rules! atomic_saturating {
+    ($($(#[$attr:meta])* $name:ident($atomic:ident, $t:ty);)*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Default)]
+            pub struct $name(core::sync::atomic::$atomic);
+
+            $(#[$attr])*
+            impl $name {
+                pub const fn new(value: $t) -> Self {
+                    Self(core::sync::atomic::$atomic::new(value))
+                }
+
+                pub fn load(&self, order: Ordering) -> SaturatingNumber<$t> {
+                    Number(self.0.load(order), Saturate)
+                }
+
+                pub fn store(&self, value: SaturatingNumber<$t>, order: Ordering) {
+                    self.0.store(value.0, order)
+                }
+
+                /// Stores `value` and returns the previous value.
+                pub fn swap(&self, value: SaturatingNumber<$t>, order: Ordering) -> SaturatingNumber<$t> {
+                    Number(self.0.swap(value.0, order), Saturate)
+                }
+
+                /// Adds `value`, saturating at `MAX`, and returns the previous
+                /// value.
+                pub fn fetch_add(&self, value: SaturatingNumber<$t>, order: Ordering) -> SaturatingNumber<$t> {
+                    self.fetch_update(order, |current| current.saturating_add(value.0))
+                }
+
+                /// Subtracts `value`, saturating at zero, and returns the
+                /// previous value.
+                pub fn fetch_sub(&self, value: SaturatingNumber<$t>, order: Ordering) -> SaturatingNumber<$t> {
+                    self.fetch_update(order, |current| current.saturating_sub(value.0))
+                }
+
+                /// Multiplies by `value`, saturating at `MAX`, and returns the
+                /// previous value.
+                pub fn fetch_mul(&self, value: SaturatingNumber<$t>, order: Ordering) -> SaturatingNumber<$t> {
+                    self.fetch_update(order, |current| current.saturating_mul(value.0))
+                }
+
+                pub fn into_inner(self) -> SaturatingNumber<$t> {
+                    Number(self.0.into_inner(), Saturate)
+                }
+
+                fn fetch_update(&self, order: Ordering, f: impl Fn($t) -> $t) -> SaturatingNumber<$t> {
+                    let previous = self
+                        .0
+                        .fetch_update(order, load_ordering(order), |current| Some(f(current)));
+                    // The closure never returns `None`, so this cannot fail.
+                    match previous {
+                        Ok(previous) | Err(previous) => Number(previous, Saturate),
+                    }
+                }
+            }
+
+            $(#[$attr])*
+            impl From<$t> for $name {
+                fn from(value: $t) -> Self {
+                    Self::new(value)
+                }
+            }
+
+            $(#[$attr])*
+            impl From<SaturatingNumber<$t>> for $name {
+                fn from(value: SaturatingNumber<$t>) -> Self {
+                    Self::new(value.0)
+                }
+            }
+
+            $(#[$attr])*
+            impl fmt::Debug for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+                }
+            }
+        )*
+    };
+}
+
+atomic_saturating! {
+    #[cfg(target_has_atomic = "32")]
+    AtomicSaturatingU32(AtomicU32, u32);
+    #[cfg(target_has_atomic = "64")]
+    AtomicSaturatingU64(AtomicU64, u64);
+    #[cfg(target_has_atomic = "ptr")]
+    AtomicSaturatingUsize(AtomicUsize, usize);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{SaturatingU32, SaturatingU64};
+    use std::{sync::Arc, thread};
+
+    #[test]
+    fn test_single_threaded() {
+        let counter = AtomicSaturatingU64::new(u64::MAX - 2);
+        assert_eq!(
+            counter.fetch_add(SaturatingU64::from(10), Ordering::Relaxed),
+            SaturatingU64::from(u64::MAX - 2)
+        );
+        assert_eq!(
+            counter.load(Ordering::Relaxed),
+            SaturatingU64::from(u64::MAX)
+        );
+        counter.fetch_add(SaturatingU64::from(1), Ordering::SeqCst);
+        assert_eq!(
+            counter.load(Ordering::SeqCst),
+            SaturatingU64::from(u64::MAX)
+        );
+
+        counter.store(SaturatingU64::from(3), Ordering::Release);
+        assert_eq!(
+            counter.fetch_sub(SaturatingU64::from(5), Ordering::AcqRel),
+            SaturatingU64::from(3)
+        );
+        assert_eq!(counter.load(Ordering::Acquire), SaturatingU64::from(0));
+
+        counter.store(SaturatingU64::from(u64::MAX / 2), Ordering::Relaxed);
+        counter.fetch_mul(SaturatingU64::from(3), Ordering::Relaxed);
+        assert_eq!(counter.into_inner(), SaturatingU64::from(u64::MAX));
+    }
+
+    #[test]
+    fn test_results_compose_with_operators() {
+        let counter = AtomicSaturatingU32::from(SaturatingU32::from(7));
+        let total = counter.load(Ordering::Relaxed) + SaturatingU32::from(u32::MAX);
+        assert_eq!(total, SaturatingU32::from(u32::MAX));
+        assert_eq!(
+            counter.swap(SaturatingU32::from(1), Ordering::Relaxed),
+            SaturatingU32::from(7)
+        );
+        assert_eq!(format!("{:?}", counter), "1");
+        assert_eq!(AtomicSaturatingUsize::default().into_inner(), 0);
+    }
+
+    #[test]
+    fn test_concurrent_adds_never_wrap() {
+        let counter = Arc::new(AtomicSaturatingU32::new(u32::MAX - 1000));
+        let threads: Vec<_> = (0..8)
+            .map(|_| {
+                let counter = Arc::clone(&counter);
+                thread::spawn(move || {
+                    for _ in 0..1000 {
+                        counter.fetch_add(SaturatingU32::from(1), Ordering::Relaxed);
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        assert_eq!(
+            counter.load(Ordering::Relaxed),
+            SaturatingU32::from(u32::MAX)
+        );
+    }
+
+    #[test]
+    fn test_concurrent_adds_are_exact_below_max() {
+        let counter = Arc::new(AtomicSaturatingU64::new(0));
+        let threads: Vec<_> = (0..8)
+            .map(|_| {
+                let counter = Arc::clone(&counter);
+                thread::spawn(move || {
+                    for _ in 0..1000 {
+                        counter.fetch_add(SaturatingU64::from(3), Ordering::Relaxed);
+                        counter.fetch_sub(SaturatingU64::from(1), Ordering::Relaxed);
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        assert_eq!(counter.load(Ordering::Relaxed), SaturatingU64::from(16000));
+    }
+}
@@ -0,0 +1,197 @@
+//! Saturating numbers whose bounds are set at compile time rather than being
+//! the limits of the underlying integer type, e.g. a percentage in `0..=100`:
+//!
+//! ```
+//! use saturating_numbers::BoundedU8;
+//!
+//! type Percentage = BoundedU8<0, 100>;
+//!
+//! let p = Percentage::clamped(70) + Percentage::clamped(50);
+//! assert_eq!(p, Percentage::MAX);
+//! assert!(Percentage::new(101).is_none());
+//! ```
+//!
+//! Const generic parameters cannot have a generic type, so there is one
+//! bounded type per supported primitive.
+
+use crate::{Number, Saturate, SaturatingNumber};
+use core::{
+    fmt,
+    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+macro_rules! bounded_type {
+    ($($name:ident: $t:ty),*) => {
+        $(
+            #[derive(PartialOrd, Ord, PartialEq, Eq, Copy, Clone)]
+            pub struct $name<const MIN: $t, const MAX: $t>(SaturatingNumber<$t>);
+
+            impl<const MIN: $t, const MAX: $t> $name<MIN, MAX> {
+                pub const MIN: Self = Self(Number(MIN, Saturate));
+                pub const MAX: Self = Self(Number(MAX, Saturate));
+
+                /// Returns `None` if `value` lies outside `MIN..=MAX`.
+                pub fn new(value: $t) -> Option<Self> {
+                    const { assert!(MIN <= MAX, "MIN must not exceed MAX") };
+                    if (MIN..=MAX).contains(&value) {
+                        Some(Self(Number(value, Saturate)))
+                    } else {
+                        None
+                    }
+                }
+
+                /// Clamps `value` into `MIN..=MAX`.
+                pub fn clamped(value: $t) -> Self {
+                    const { assert!(MIN <= MAX, "MIN must not exceed MAX") };
+                    Self(Number(value.clamp(MIN, MAX), Saturate))
+                }
+
+                pub fn get(self) -> $t {
+                    self.0 .0
+                }
+            }
+
+            impl<const MIN: $t, const MAX: $t> From<$name<MIN, MAX>> for SaturatingNumber<$t> {
+                fn from(input: $name<MIN, MAX>) -> Self {
+                    input.0
+                }
+            }
+
+            // The arithmetic uses the primitive saturating operators, since
+            // hitting the limits of `$t` is not an event of its own here.
+
+            impl<const MIN: $t, const MAX: $t> Add for $name<MIN, MAX> {
+                type Output = Self;
+
+                fn add(self, rhs: Self) -> Self {
+                    Self::clamped(self.get().saturating_add(rhs.get()))
+                }
+            }
+
+            impl<const MIN: $t, const MAX: $t> AddAssign for $name<MIN, MAX> {
+                fn add_assign(&mut self, rhs: Self) {
+                    *self = *self + rhs
+                }
+            }
+
+            impl<const MIN: $t, const MAX: $t> Sub for $name<MIN, MAX> {
+                type Output = Self;
+
+                fn sub(self, rhs: Self) -> Self {
+                    Self::clamped(self.get().saturating_sub(rhs.get()))
+                }
+            }
+
+            impl<const MIN: $t, const MAX: $t> SubAssign for $name<MIN, MAX> {
+                fn sub_assign(&mut self, rhs: Self) {
+                    *self = *self - rhs
+                }
+            }
+
+            impl<const MIN: $t, const MAX: $t> Mul for $name<MIN, MAX> {
+                type Output = Self;
+
+                fn mul(self, rhs: Self) -> Self {
+                    Self::clamped(self.get().saturating_mul(rhs.get()))
+                }
+            }
+
+            impl<const MIN: $t, const MAX: $t> MulAssign for $name<MIN, MAX> {
+                fn mul_assign(&mut self, rhs: Self) {
+                    *self = *self * rhs
+                }
+            }
+
+            impl<const MIN: $t, const MAX: $t> fmt::Debug for $name<MIN, MAX> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{:?}", self.0)
+                }
+            }
+        )*
+    };
+}
+
+bounded_type!(
+    BoundedU8: u8,
+    BoundedU16: u16,
+    BoundedU32: u32,
+    BoundedU64: u64,
+    BoundedU128: u128,
+    BoundedUsize: usize,
+    BoundedI8: i8,
+    BoundedI16: i16,
+    BoundedI32: i32,
+    BoundedI64: i64,
+    BoundedI128: i128,
+    BoundedIsize: isize
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    type Percentage = BoundedU8<0, 100>;
+    type Priority = BoundedI32<1, 7>;
+
+    #[test]
+    fn test_constructors() {
+        assert_eq!(Percentage::new(100).map(Percentage::get), Some(100));
+        assert_eq!(Percentage::new(101), None);
+        assert_eq!(Percentage::clamped(255), Percentage::MAX);
+        assert_eq!(Priority::new(0), None);
+        assert_eq!(Priority::clamped(-5), Priority::MIN);
+        assert_eq!(Priority::MIN.get(), 1);
+        assert_eq!(Priority::MAX.get(), 7);
+    }
+
+    #[test]
+    fn test_arithmetic_clamps_to_bounds() {
+        assert_eq!(
+            Percentage::clamped(60) + Percentage::clamped(60),
+            Percentage::MAX
+        );
+        assert_eq!(
+            Percentage::clamped(10) - Percentage::clamped(60),
+            Percentage::MIN
+        );
+        assert_eq!(
+            Percentage::clamped(20) * Percentage::clamped(20),
+            Percentage::MAX
+        );
+        assert_eq!(Priority::clamped(2) - Priority::clamped(3), Priority::MIN);
+        assert_eq!(
+            Priority::clamped(2) * Priority::clamped(3),
+            Priority::clamped(6)
+        );
+
+        let mut retries = BoundedU32::<0, 10>::clamped(8);
+        retries += BoundedU32::clamped(1);
+        assert_eq!(retries.get(), 9);
+        retries += BoundedU32::clamped(5);
+        assert_eq!(retries, BoundedU32::MAX);
+        retries -= BoundedU32::clamped(4);
+        retries *= BoundedU32::clamped(2);
+        assert_eq!(retries, BoundedU32::MAX);
+    }
+
+    #[test]
+    fn test_against_reference() {
+        type Small = BoundedI8<-20, 30>;
+        for a in -20..=30i8 {
+            for b in -20..=30i8 {
+                let (x, y) = (Small::clamped(a), Small::clamped(b));
+                let clamp = |v: i32| v.clamp(-20, 30) as i8;
+                let (wa, wb) = (i32::from(a), i32::from(b));
+                assert_eq!((x + y).get(), clamp(wa + wb));
+                assert_eq!((x - y).get(), clamp(wa - wb));
+                assert_eq!((x * y).get(), clamp(wa * wb));
+            }
+        }
+    }
+
+    #[test]
+    fn test_into_saturating_number() {
+        let p = Percentage::clamped(42);
+        assert_eq!(crate::SaturatingU8::from(p), crate::SaturatingU8::from(42));
+    }
+}
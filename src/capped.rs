@@ -0,0 +1,250 @@
+//! Saturating numbers whose upper bound is only known at runtime, e.g. a quota
+//! read from a config file.
+
+use crate::{HasSaturatingAdd, HasSaturatingMul, HasSaturatingSub};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+/// A value that saturates at a runtime `cap` as well as at the bounds of `T`.
+///
+/// When both operands of a binary operator are `Capped`, the result carries
+/// the smaller of the two caps.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Capped<T> {
+    value: T,
+    cap: T,
+}
+
+impl<T: PartialOrd + Clone> Capped<T> {
+    /// Creates a new value, clamping `value` to `cap`.
+    pub fn with_cap(value: T, cap: T) -> Self {
+        let value = if value > cap { cap.clone() } else { value };
+        Self { value, cap }
+    }
+
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    pub fn cap(&self) -> &T {
+        &self.cap
+    }
+
+    /// Returns how far the value is from its cap.
+    pub fn remaining(&self) -> T
+    where
+        T: HasSaturatingSub,
+    {
+        self.cap.do_saturating_sub(&self.value)
+    }
+
+    fn smaller_cap(self, rhs: &Self) -> T {
+        if rhs.cap < self.cap {
+            rhs.cap.clone()
+        } else {
+            self.cap
+        }
+    }
+
+    // The in-place operators update `value` and `cap` directly, so that inner
+    // types owning heap storage are only cloned when clamping to the cap.
+
+    fn lower_cap(&mut self, cap: T) {
+        if cap < self.cap {
+            self.cap = cap;
+        }
+    }
+
+    fn clamp_to_cap(&mut self) {
+        if self.value > self.cap {
+            self.value = self.cap.clone();
+        }
+    }
+}
+
+impl<T: PartialOrd + Clone + HasSaturatingAdd> Add for Capped<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let value = self.value.do_saturating_add(&rhs.value);
+        Self::with_cap(value, self.smaller_cap(&rhs))
+    }
+}
+
+impl<T: PartialOrd + Clone + HasSaturatingAdd> AddAssign for Capped<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.value.do_saturating_add_assign(&rhs.value);
+        self.lower_cap(rhs.cap);
+        self.clamp_to_cap();
+    }
+}
+
+impl<T: PartialOrd + Clone + HasSaturatingAdd> Add<T> for Capped<T> {
+    type Output = Self;
+
+    fn add(self, rhs: T) -> Self {
+        Self::with_cap(self.value.do_saturating_add(&rhs), self.cap)
+    }
+}
+
+impl<T: PartialOrd + Clone + HasSaturatingAdd> AddAssign<T> for Capped<T> {
+    fn add_assign(&mut self, rhs: T) {
+        self.value.do_saturating_add_assign(&rhs);
+        self.clamp_to_cap();
+    }
+}
+
+impl<T: PartialOrd + Clone + HasSaturatingSub> Sub for Capped<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let value = self.value.do_saturating_sub(&rhs.value);
+        Self::with_cap(value, self.smaller_cap(&rhs))
+    }
+}
+
+impl<T: PartialOrd + Clone + HasSaturatingSub> SubAssign for Capped<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value.do_saturating_sub_assign(&rhs.value);
+        self.lower_cap(rhs.cap);
+        self.clamp_to_cap();
+    }
+}
+
+impl<T: PartialOrd + Clone + HasSaturatingSub> Sub<T> for Capped<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: T) -> Self {
+        Self::with_cap(self.value.do_saturating_sub(&rhs), self.cap)
+    }
+}
+
+impl<T: PartialOrd + Clone + HasSaturatingSub> SubAssign<T> for Capped<T> {
+    fn sub_assign(&mut self, rhs: T) {
+        self.value.do_saturating_sub_assign(&rhs);
+        self.clamp_to_cap();
+    }
+}
+
+impl<T: PartialOrd + Clone + HasSaturatingMul> Mul for Capped<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let value = self.value.do_saturating_mul(&rhs.value);
+        Self::with_cap(value, self.smaller_cap(&rhs))
+    }
+}
+
+impl<T: PartialOrd + Clone + HasSaturatingMul> MulAssign for Capped<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.value.do_saturating_mul_assign(&rhs.value);
+        self.lower_cap(rhs.cap);
+        self.clamp_to_cap();
+    }
+}
+
+impl<T: PartialOrd + Clone + HasSaturatingMul> Mul<T> for Capped<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self::with_cap(self.value.do_saturating_mul(&rhs), self.cap)
+    }
+}
+
+impl<T: PartialOrd + Clone + HasSaturatingMul> MulAssign<T> for Capped<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.value.do_saturating_mul_assign(&rhs);
+        self.clamp_to_cap();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Saturation;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_with_cap() {
+        let quota = Capped::with_cap(150u64, 100);
+        assert_eq!(*quota.get(), 100);
+        assert_eq!(*quota.cap(), 100);
+        assert_eq!(quota.remaining(), 0);
+        assert_eq!(Capped::with_cap(30u64, 100).remaining(), 70);
+    }
+
+    #[test]
+    fn test_arithmetic_saturates_at_cap() {
+        let mut used = Capped::with_cap(90u32, 100);
+        used += 5;
+        assert_eq!(*used.get(), 95);
+        used += 10;
+        assert_eq!(*used.get(), 100);
+        used -= 200;
+        assert_eq!(*used.get(), 0);
+        assert_eq!(*(Capped::with_cap(30u32, 100) * 4).get(), 100);
+        assert_eq!(*(Capped::with_cap(u32::MAX, u32::MAX) + 1).get(), u32::MAX);
+        assert_eq!(
+            *(Capped::with_cap(-5i32, 10) - Capped::with_cap(i32::MAX, i32::MAX)).get(),
+            i32::MIN
+        );
+    }
+
+    #[test]
+    fn test_mismatched_caps_take_smaller() {
+        let a = Capped::with_cap(40u64, 100);
+        let b = Capped::with_cap(40u64, 50);
+        let sum = a + b;
+        assert_eq!(*sum.cap(), 50);
+        assert_eq!(*sum.get(), 50);
+        let product = b * a;
+        assert_eq!(*product.cap(), 50);
+        assert_eq!(*product.get(), 50);
+        let mut c = a;
+        c -= b;
+        assert_eq!(c, Capped::with_cap(0, 50));
+        c += Capped::with_cap(70, 100);
+        assert_eq!(c, Capped::with_cap(50, 50));
+    }
+
+    /// Counts its clones, to check that the in-place operators only clone
+    /// when clamping to the cap.
+    #[derive(Debug, PartialEq, PartialOrd)]
+    struct Counted(u32);
+
+    static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+    impl Clone for Counted {
+        fn clone(&self) -> Self {
+            CLONES.fetch_add(1, Ordering::Relaxed);
+            Counted(self.0)
+        }
+    }
+
+    impl HasSaturatingAdd for Counted {
+        fn do_saturating_add(&self, rhs: &Self) -> Self {
+            Counted(self.0.saturating_add(rhs.0))
+        }
+
+        fn do_saturating_add_reporting(&self, rhs: &Self) -> (Self, Saturation) {
+            let (sum, saturation) = self.0.do_saturating_add_reporting(&rhs.0);
+            (Counted(sum), saturation)
+        }
+
+        fn do_saturating_add_assign(&mut self, rhs: &Self) {
+            self.0 = self.0.saturating_add(rhs.0);
+        }
+    }
+
+    #[test]
+    fn test_assign_does_not_clone() {
+        let mut used = Capped::with_cap(Counted(10), Counted(100));
+        used += Counted(20);
+        used += Capped::with_cap(Counted(30), Counted(80));
+        assert_eq!(used.get().0, 60);
+        assert_eq!(used.cap().0, 80);
+        assert_eq!(CLONES.load(Ordering::Relaxed), 0);
+        used += Counted(50);
+        assert_eq!(used.get().0, 80);
+        assert_eq!(CLONES.load(Ordering::Relaxed), 1);
+    }
+}
@@ -1,141 +1,1292 @@
 //! This crate implements a generic type: SaturatingNumber<T> which can be used
 //! to define saturating arthmetic on the underlying integer types. It then also
-//! exposes SaturatingU32, SaturatingU64, SaturatingU128 type aliases.
+//! exposes the unsigned SaturatingU8 through SaturatingU128 and SaturatingUsize
+//! type aliases as well as the signed SaturatingI8 through SaturatingI128 and
+//! SaturatingIsize aliases. Each alias has `const fn` `new`, `get`, `add`,
+//! `sub` and `mul` along with `MIN`, `MAX`, `ZERO` and `ONE`, so limits can be
+//! computed in `const` items and statics. `SaturatingU256` and `SaturatingU512`
+//! wrap the built-in `U256` and `U512` multi-limb integers, and
+//! `SaturatingUint<BITS>` saturates at `2^BITS - 1` for odd widths such as
+//! `SaturatingU24`.
+//!
+//! `SaturatingNumber<T>` is `Number<T, Saturate>`. The `Wrap`, `Panic` and
+//! `Checked` policies swap saturation for wrapping, panicking or poisoning the
+//! result, e.g. while debugging a module. Every operator, `Sum`, `Product`,
+//! `FromStr` and the `serde` and `num-traits` impls work under each policy.
+//! Dividing by zero with `/` or `%` panics under every policy; `div_with` and
+//! `rem_with` take a `DivByZero` that saturates or returns zero instead.
+//!
+//! For values whose domain is narrower than their type, `BoundedU8` and friends
+//! saturate at bounds fixed at compile time instead, while `Capped<T>` takes its
+//! upper bound at runtime. `Tracked<SaturatingNumber<T>>` remembers whether any
+//! operation that produced it had to clamp. `AtomicSaturatingU64` and friends
+//! are counters that can be shared between threads, and
+//! `ShardedSaturatingCounter` spreads a heavily contended counter over shards.
+//!
+//! The crate is `no_std`. The `std` feature adds `std::error::Error` impls and
+//! `ShardedSaturatingCounter`, which needs thread-locals.
+//! In debug builds, the `strict-debug` feature makes every operator on
+//! saturating numbers, as well as `pow`, `abs`, `Sum` and `Product`, panic
+//! whenever it clamps. The `*_reporting` methods, `div_with` by zero with
+//! `DivByZero::Saturate`, `Tracked`, `Capped`, the bounded types, the
+//! `num-traits` `Saturating*` traits and `ShardedSaturatingCounter::sum` still
+//! saturate silently.
+//!
+//! With the `serde` feature, `SaturatingNumber<T>` serializes like `T`; see
+//! `serde_support` for lenient and string-encoded alternatives. The
+//! `num-traits` feature implements the `num-traits` numeric traits.
 
-use std::{
-    cmp::{Eq, Ord, PartialEq, PartialOrd},
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+mod atomic;
+mod bounded;
+mod capped;
+#[cfg(feature = "num-traits")]
+mod num_traits_support;
+mod parse;
+mod policy;
+#[cfg(feature = "serde")]
+pub mod serde_support;
+#[cfg(all(any(feature = "std", test), target_has_atomic = "64"))]
+mod sharded;
+mod std_interop;
+#[cfg(test)]
+mod test_util;
+mod tracked;
+mod uint;
+mod wide;
+
+#[cfg(target_has_atomic = "32")]
+pub use atomic::AtomicSaturatingU32;
+#[cfg(target_has_atomic = "64")]
+pub use atomic::AtomicSaturatingU64;
+#[cfg(target_has_atomic = "ptr")]
+pub use atomic::AtomicSaturatingUsize;
+pub use bounded::{
+    BoundedI128, BoundedI16, BoundedI32, BoundedI64, BoundedI8, BoundedIsize, BoundedU128,
+    BoundedU16, BoundedU32, BoundedU64, BoundedU8, BoundedUsize,
+};
+pub use capped::Capped;
+pub use parse::{HasSaturatingParse, ParseErrorKind, ParseSaturatingError};
+pub use policy::{
+    AbsPolicy, AddPolicy, Checked, DivPolicy, MulPolicy, NegPolicy, OverflowPolicy, Panic,
+    PowPolicy, Saturate, ShlPolicy, SubPolicy, Wrap,
+};
+#[cfg(all(any(feature = "std", test), target_has_atomic = "64"))]
+pub use sharded::ShardedSaturatingCounter;
+pub use tracked::Tracked;
+pub use uint::{Bits, Uint, UintStorage};
+pub use wide::{U256, U512};
+
+use crate::policy::STRICT_DEBUG;
+use core::{
+    cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd},
+    convert::TryFrom,
     fmt,
-    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+    iter::{Product, Sum},
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Shl, ShlAssign, Shr,
+        ShrAssign, Sub, SubAssign,
+    },
 };
 
+/// Which bound, if any, a saturating operation clamped its result to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Saturation {
+    /// The result is exact.
+    None,
+    /// The result was clamped to the maximum.
+    Upper,
+    /// The result was clamped to the minimum.
+    Lower,
+}
+
 pub trait HasSaturatingAdd {
-    fn do_saturating_add(&self, rhs: Self) -> Self;
+    fn do_saturating_add(&self, rhs: &Self) -> Self;
+
+    /// Like `do_saturating_add`, but also reports which bound, if any, the
+    /// result was clamped to.
+    fn do_saturating_add_reporting(&self, rhs: &Self) -> (Self, Saturation)
+    where
+        Self: Sized;
+
+    /// Adds `rhs` in place. Inner types that own heap storage can override
+    /// this to reuse it instead of allocating a new value.
+    fn do_saturating_add_assign(&mut self, rhs: &Self)
+    where
+        Self: Sized,
+    {
+        *self = self.do_saturating_add(rhs);
+    }
+
+    /// Formats `self` as an operand in the panic messages of the
+    /// `strict-debug` feature. The default shows `_`, so that `Self` need not
+    /// implement `Debug` or `Display`.
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("_")
+    }
 }
 
 pub trait HasSaturatingSub {
-    fn do_saturating_sub(&self, rhs: Self) -> Self;
+    fn do_saturating_sub(&self, rhs: &Self) -> Self;
+
+    /// Like `do_saturating_sub`, but also reports which bound, if any, the
+    /// result was clamped to.
+    fn do_saturating_sub_reporting(&self, rhs: &Self) -> (Self, Saturation)
+    where
+        Self: Sized;
+
+    /// Subtracts `rhs` in place. Inner types that own heap storage can
+    /// override this to reuse it instead of allocating a new value.
+    fn do_saturating_sub_assign(&mut self, rhs: &Self)
+    where
+        Self: Sized,
+    {
+        *self = self.do_saturating_sub(rhs);
+    }
+
+    /// See `HasSaturatingAdd::fmt_operand`.
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("_")
+    }
 }
 
 pub trait HasSaturatingMul {
-    fn do_saturating_mul(&self, rhs: Self) -> Self;
+    fn do_saturating_mul(&self, rhs: &Self) -> Self;
+
+    /// Like `do_saturating_mul`, but also reports which bound, if any, the
+    /// result was clamped to.
+    fn do_saturating_mul_reporting(&self, rhs: &Self) -> (Self, Saturation)
+    where
+        Self: Sized;
+
+    /// Multiplies by `rhs` in place. Inner types that own heap storage can
+    /// override this to reuse it instead of allocating a new value.
+    fn do_saturating_mul_assign(&mut self, rhs: &Self)
+    where
+        Self: Sized,
+    {
+        *self = self.do_saturating_mul(rhs);
+    }
+
+    /// See `HasSaturatingAdd::fmt_operand`.
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("_")
+    }
+}
+
+pub trait HasWrappingAdd {
+    fn do_wrapping_add(&self, rhs: &Self) -> Self;
+}
+
+pub trait HasWrappingSub {
+    fn do_wrapping_sub(&self, rhs: &Self) -> Self;
+}
+
+pub trait HasWrappingMul {
+    fn do_wrapping_mul(&self, rhs: &Self) -> Self;
+}
+
+pub trait HasWrappingPow {
+    fn do_wrapping_pow(&self, exp: u32) -> Self;
+}
+
+pub trait HasWrappingShl {
+    /// Shifts left, discarding the bits shifted out. Shifting by the bit
+    /// width or more gives zero.
+    fn do_wrapping_shl(&self, rhs: u32) -> Self;
+}
+
+pub trait HasWrappingDiv {
+    /// Divides by `rhs`, wrapping signed `MIN / -1` around to `MIN` and
+    /// handling a zero divisor according to `on_zero`.
+    fn do_wrapping_div(&self, rhs: &Self, on_zero: DivByZero) -> Self;
+}
+
+pub trait HasWrappingNeg {
+    fn do_wrapping_neg(&self) -> Self;
+}
+
+pub trait HasWrappingAbs {
+    fn do_wrapping_abs(&self) -> Self;
+}
+
+pub trait HasSaturatingSum: HasSaturatingAdd + Sized {
+    fn zero() -> Self;
+
+    /// Returns true if no further saturating addition can change the value,
+    /// which lets `Sum` stop early.
+    fn absorbs_add(&self) -> bool;
+}
+
+pub trait HasSaturatingProduct: HasSaturatingMul + Sized {
+    fn one() -> Self;
+
+    /// Returns true if no further saturating multiplication can change the
+    /// value, which lets `Product` stop early.
+    fn absorbs_mul(&self) -> bool;
+}
+
+pub trait HasSaturatingBounds {
+    /// Returns true if the value sits at a bound that saturating operations
+    /// clamp to. Zero does not count, even though it is the minimum of the
+    /// unsigned types.
+    fn is_at_bound(&self) -> bool;
+}
+
+pub trait HasSaturatingPow {
+    fn do_saturating_pow(&self, exp: u32) -> Self;
+
+    /// Like `do_saturating_pow`, but also reports which bound, if any, the
+    /// result was clamped to.
+    fn do_saturating_pow_reporting(&self, exp: u32) -> (Self, Saturation)
+    where
+        Self: Sized;
+
+    /// See `HasSaturatingAdd::fmt_operand`.
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("_")
+    }
+}
+
+pub trait HasSaturatingShl {
+    fn do_saturating_shl(&self, rhs: u32) -> Self;
+
+    /// Like `do_saturating_shl`, but also reports which bound, if any, the
+    /// result was clamped to.
+    fn do_saturating_shl_reporting(&self, rhs: u32) -> (Self, Saturation)
+    where
+        Self: Sized;
+
+    /// See `HasSaturatingAdd::fmt_operand`.
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("_")
+    }
+}
+
+pub trait HasSaturatingShr {
+    fn do_saturating_shr(&self, rhs: u32) -> Self;
+}
+
+/// What a saturating division or remainder does when the divisor is zero.
+///
+/// The `/`, `/=`, `%` and `%=` operators always behave like `Panic`, as they
+/// do for primitives. To saturate or return zero instead, call
+/// `Number::div_with` or `Number::rem_with`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DivByZero {
+    /// Panic, like the primitive `/` and `%` operators.
+    Panic,
+    /// Divisions saturate to `MAX` (or `MIN` for a negative dividend) and
+    /// remainders return the dividend unchanged.
+    Saturate,
+    /// Both divisions and remainders return zero.
+    Zero,
+}
+
+pub trait HasSaturatingDiv {
+    fn do_saturating_div(&self, rhs: &Self, on_zero: DivByZero) -> Self;
+    fn do_saturating_rem(&self, rhs: &Self, on_zero: DivByZero) -> Self;
+
+    /// Like `do_saturating_div`, but also reports which bound, if any, the
+    /// result was clamped to. A zero divisor only counts as saturation under
+    /// `DivByZero::Saturate`.
+    fn do_saturating_div_reporting(&self, rhs: &Self, on_zero: DivByZero) -> (Self, Saturation)
+    where
+        Self: Sized;
+
+    /// See `HasSaturatingAdd::fmt_operand`.
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("_")
+    }
 }
 
-#[derive(PartialOrd, Ord, PartialEq, Eq, Copy, Clone)]
-pub struct SaturatingNumber<T>(T);
+pub trait HasSaturatingNeg {
+    fn do_saturating_neg(&self) -> Self;
 
-impl<T> From<T> for SaturatingNumber<T> {
+    /// Like `do_saturating_neg`, but also reports which bound, if any, the
+    /// result was clamped to.
+    fn do_saturating_neg_reporting(&self) -> (Self, Saturation)
+    where
+        Self: Sized;
+
+    /// See `HasSaturatingAdd::fmt_operand`.
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("_")
+    }
+}
+
+pub trait HasSaturatingAbs {
+    fn do_saturating_abs(&self) -> Self;
+
+    /// Like `do_saturating_abs`, but also reports which bound, if any, the
+    /// result was clamped to.
+    fn do_saturating_abs_reporting(&self) -> (Self, Saturation)
+    where
+        Self: Sized;
+
+    /// See `HasSaturatingAdd::fmt_operand`.
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("_")
+    }
+}
+
+/// Conversion that clamps values which do not fit in `Self` to its nearest
+/// bound instead of failing or wrapping.
+pub trait SaturatingFrom<U>: Sized {
+    fn saturating_from(value: U) -> Self;
+}
+
+/// The counterpart to `SaturatingFrom`, implemented for every type whose
+/// target implements `SaturatingFrom`.
+pub trait SaturatingInto<U> {
+    fn saturating_into(self) -> U;
+}
+
+impl<T, U: SaturatingFrom<T>> SaturatingInto<U> for T {
+    fn saturating_into(self) -> U {
+        U::saturating_from(self)
+    }
+}
+
+/// An integer whose arithmetic operators handle overflow according to the
+/// policy `P`: `Saturate`, `Wrap`, `Panic` or `Checked`.
+///
+/// Comparisons only look at the values. A poisoned `Number<T, Checked>` is
+/// treated like NaN: it neither equals nor compares to anything, including
+/// itself, so `Eq` and `Ord` are only implemented for the other policies.
+#[derive(Copy, Clone)]
+pub struct Number<T, P = Saturate>(T, P);
+
+/// The saturating `Number`, which clamps results to the bounds of `T`.
+pub type SaturatingNumber<T> = Number<T, Saturate>;
+
+impl<T, P: OverflowPolicy> From<T> for Number<T, P> {
     fn from(input: T) -> Self {
-        Self(input)
+        Self(input, P::default())
     }
 }
 
-impl<T: Add<Output = T> + HasSaturatingAdd> Add for SaturatingNumber<T> {
-    type Output = Self;
+impl<T, U: SaturatingFrom<T>> SaturatingFrom<SaturatingNumber<T>> for SaturatingNumber<U> {
+    fn saturating_from(value: SaturatingNumber<T>) -> Self {
+        Self(U::saturating_from(value.0), Saturate)
+    }
+}
+
+macro_rules! impl_binop {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, $Policy:ident, $apply:ident, $apply_assign:ident, $verb:literal) => {
+        impl<T: $Op<Output = T>, P: $Policy<T>> $Op for Number<T, P> {
+            type Output = Self;
+
+            fn $op(mut self, rhs: Self) -> Self {
+                let overflow = P::$apply_assign(&mut self.0, &rhs.0);
+                self.1 = self.1.merge(rhs.1, overflow, $verb);
+                self
+            }
+        }
+
+        impl<T: $Op<Output = T>, P: $Policy<T>> $OpAssign for Number<T, P> {
+            fn $op_assign(&mut self, rhs: Self) {
+                let overflow = P::$apply_assign(&mut self.0, &rhs.0);
+                self.1 = self.1.merge(rhs.1, overflow, $verb);
+            }
+        }
+
+        impl<'a, T: $Op<Output = T>, P: $Policy<T>> $Op<&'a Number<T, P>> for Number<T, P> {
+            type Output = Number<T, P>;
+
+            fn $op(mut self, rhs: &'a Number<T, P>) -> Number<T, P> {
+                let overflow = P::$apply_assign(&mut self.0, &rhs.0);
+                self.1 = self.1.merge(rhs.1, overflow, $verb);
+                self
+            }
+        }
+
+        impl<'a, T: $Op<Output = T>, P: $Policy<T>> $Op<Number<T, P>> for &'a Number<T, P> {
+            type Output = Number<T, P>;
+
+            fn $op(self, rhs: Number<T, P>) -> Number<T, P> {
+                let (value, overflow) = P::$apply(&self.0, &rhs.0);
+                Number(value, self.1.merge(rhs.1, overflow, $verb))
+            }
+        }
+
+        impl<'a, 'b, T: $Op<Output = T>, P: $Policy<T>> $Op<&'b Number<T, P>> for &'a Number<T, P> {
+            type Output = Number<T, P>;
+
+            fn $op(self, rhs: &'b Number<T, P>) -> Number<T, P> {
+                let (value, overflow) = P::$apply(&self.0, &rhs.0);
+                Number(value, self.1.merge(rhs.1, overflow, $verb))
+            }
+        }
+
+        impl<'a, T: $Op<Output = T>, P: $Policy<T>> $OpAssign<&'a Number<T, P>> for Number<T, P> {
+            fn $op_assign(&mut self, rhs: &'a Number<T, P>) {
+                let overflow = P::$apply_assign(&mut self.0, &rhs.0);
+                self.1 = self.1.merge(rhs.1, overflow, $verb);
+            }
+        }
+
+        impl<T: $Op<Output = T>, P: $Policy<T>> $Op<T> for Number<T, P> {
+            type Output = Self;
+
+            fn $op(mut self, rhs: T) -> Self {
+                let overflow = P::$apply_assign(&mut self.0, &rhs);
+                self.1 = self.1.merge(P::default(), overflow, $verb);
+                self
+            }
+        }
+
+        impl<T: $Op<Output = T>, P: $Policy<T>> $OpAssign<T> for Number<T, P> {
+            fn $op_assign(&mut self, rhs: T) {
+                let overflow = P::$apply_assign(&mut self.0, &rhs);
+                self.1 = self.1.merge(P::default(), overflow, $verb);
+            }
+        }
+    };
+}
+
+impl_binop!(
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    AddPolicy,
+    policy_add,
+    policy_add_assign,
+    "add"
+);
+impl_binop!(
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    SubPolicy,
+    policy_sub,
+    policy_sub_assign,
+    "subtract"
+);
+impl_binop!(
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    MulPolicy,
+    policy_mul,
+    policy_mul_assign,
+    "multiply"
+);
+
+// A poisoned value is not known to be any particular `T`, so like NaN it
+// neither equals nor compares to one, nor to another number.
+
+impl<T: PartialEq, P: OverflowPolicy> PartialEq for Number<T, P> {
+    fn eq(&self, other: &Self) -> bool {
+        !self.1.is_poisoned() && !other.1.is_poisoned() && self.0 == other.0
+    }
+}
+
+impl<T: PartialOrd, P: OverflowPolicy> PartialOrd for Number<T, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.1.is_poisoned() || other.1.is_poisoned() {
+            None
+        } else {
+            self.0.partial_cmp(&other.0)
+        }
+    }
+}
+
+macro_rules! impl_total_order {
+    ($($policy:ty),*) => {
+        $(
+            impl<T: Eq> Eq for Number<T, $policy> {}
+
+            impl<T: Ord> Ord for Number<T, $policy> {
+                fn cmp(&self, other: &Self) -> Ordering {
+                    self.0.cmp(&other.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_total_order!(Saturate, Wrap, Panic);
+
+impl<T: PartialEq, P: OverflowPolicy> PartialEq<T> for Number<T, P> {
+    fn eq(&self, other: &T) -> bool {
+        !self.1.is_poisoned() && self.0 == *other
+    }
+}
 
-    fn add(self, rhs: Self) -> Self {
-        Self(self.0.do_saturating_add(rhs.0))
+impl<T: PartialOrd, P: OverflowPolicy> PartialOrd<T> for Number<T, P> {
+    fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+        if self.1.is_poisoned() {
+            None
+        } else {
+            self.0.partial_cmp(other)
+        }
     }
 }
 
-impl<T: Add<Output = T> + HasSaturatingAdd> AddAssign for SaturatingNumber<T> {
-    fn add_assign(&mut self, rhs: Self) {
-        self.0 = self.0.do_saturating_add(rhs.0)
+// The iterator impls stop consuming their input as soon as the accumulator can
+// no longer change, if the policy only saturates. With strict-debug they check
+// every item, so that whether a sum panics does not depend on the order of its
+// items.
+
+impl<T: Add<Output = T> + HasSaturatingSum, P: AddPolicy<T>> Sum for Number<T, P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let mut total = Self::from(T::zero());
+        for item in iter {
+            total += item;
+            if P::SATURATES && !STRICT_DEBUG && total.0.absorbs_add() {
+                break;
+            }
+        }
+        total
+    }
+}
+
+impl<'a, T: 'a + Add<Output = T> + HasSaturatingSum, P: 'a + AddPolicy<T>> Sum<&'a Number<T, P>>
+    for Number<T, P>
+{
+    fn sum<I: Iterator<Item = &'a Number<T, P>>>(iter: I) -> Self {
+        let mut total = Self::from(T::zero());
+        for item in iter {
+            total += item;
+            if P::SATURATES && !STRICT_DEBUG && total.0.absorbs_add() {
+                break;
+            }
+        }
+        total
     }
 }
 
-impl<T: Sub<Output = T> + HasSaturatingSub> Sub for SaturatingNumber<T> {
+impl<T: Mul<Output = T> + HasSaturatingProduct, P: MulPolicy<T>> Product for Number<T, P> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let mut total = Self::from(T::one());
+        for item in iter {
+            total *= item;
+            if P::SATURATES && !STRICT_DEBUG && total.0.absorbs_mul() {
+                break;
+            }
+        }
+        total
+    }
+}
+
+impl<'a, T: 'a + Mul<Output = T> + HasSaturatingProduct, P: 'a + MulPolicy<T>>
+    Product<&'a Number<T, P>> for Number<T, P>
+{
+    fn product<I: Iterator<Item = &'a Number<T, P>>>(iter: I) -> Self {
+        let mut total = Self::from(T::one());
+        for item in iter {
+            total *= item;
+            if P::SATURATES && !STRICT_DEBUG && total.0.absorbs_mul() {
+                break;
+            }
+        }
+        total
+    }
+}
+
+impl<T: HasSaturatingAdd> SaturatingNumber<T> {
+    /// Adds `rhs` and reports which bound, if any, the sum was clamped to.
+    pub fn add_reporting(self, rhs: Self) -> (Self, Saturation) {
+        let (value, saturation) = self.0.do_saturating_add_reporting(&rhs.0);
+        (Self(value, Saturate), saturation)
+    }
+}
+
+impl<T: HasSaturatingSub> SaturatingNumber<T> {
+    /// Subtracts `rhs` and reports which bound, if any, the difference was
+    /// clamped to.
+    pub fn sub_reporting(self, rhs: Self) -> (Self, Saturation) {
+        let (value, saturation) = self.0.do_saturating_sub_reporting(&rhs.0);
+        (Self(value, Saturate), saturation)
+    }
+}
+
+impl<T: HasSaturatingMul> SaturatingNumber<T> {
+    /// Multiplies by `rhs` and reports which bound, if any, the product was
+    /// clamped to.
+    pub fn mul_reporting(self, rhs: Self) -> (Self, Saturation) {
+        let (value, saturation) = self.0.do_saturating_mul_reporting(&rhs.0);
+        (Self(value, Saturate), saturation)
+    }
+}
+
+impl<T, P: PowPolicy<T>> Number<T, P> {
+    /// Raises the number to the power of `exp`, handling overflow according
+    /// to the policy.
+    pub fn pow(self, exp: u32) -> Self {
+        let (value, overflow) = P::policy_pow(&self.0, exp);
+        Self(value, self.1.merge(P::default(), overflow, "multiply"))
+    }
+}
+
+impl<T: Shl<u32, Output = T>, P: ShlPolicy<T>> Shl<u32> for Number<T, P> {
     type Output = Self;
 
-    fn sub(self, rhs: Self) -> Self {
-        Self(self.0.do_saturating_sub(rhs.0))
+    fn shl(mut self, rhs: u32) -> Self {
+        self <<= rhs;
+        self
     }
 }
 
-impl<T: Sub<Output = T> + HasSaturatingSub> SubAssign for SaturatingNumber<T> {
-    fn sub_assign(&mut self, rhs: Self) {
-        self.0 = self.0.do_saturating_sub(rhs.0)
+impl<T: Shl<u32, Output = T>, P: ShlPolicy<T>> ShlAssign<u32> for Number<T, P> {
+    fn shl_assign(&mut self, rhs: u32) {
+        let (value, overflow) = P::policy_shl(&self.0, rhs);
+        self.0 = value;
+        self.1 = self.1.merge(P::default(), overflow, "shift left");
     }
 }
 
-impl<T: Mul<Output = T> + HasSaturatingMul> Mul for SaturatingNumber<T> {
+// Right shifts and remainders cannot overflow, so every policy computes them
+// the same way.
+
+impl<T: Shr<u32, Output = T> + HasSaturatingShr, P> Shr<u32> for Number<T, P> {
     type Output = Self;
 
-    fn mul(self, rhs: Self) -> Self {
-        Self(self.0.do_saturating_mul(rhs.0))
+    fn shr(self, rhs: u32) -> Self {
+        Self(self.0.do_saturating_shr(rhs), self.1)
     }
 }
 
-impl<T: Mul<Output = T> + HasSaturatingMul> MulAssign for SaturatingNumber<T> {
-    fn mul_assign(&mut self, rhs: Self) {
-        self.0 = self.0.do_saturating_mul(rhs.0)
+impl<T: Shr<u32, Output = T> + HasSaturatingShr, P> ShrAssign<u32> for Number<T, P> {
+    fn shr_assign(&mut self, rhs: u32) {
+        self.0 = self.0.do_saturating_shr(rhs)
     }
 }
 
-impl<T: fmt::Debug> fmt::Debug for SaturatingNumber<T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self.0)
+impl<T: Div<Output = T>, P: DivPolicy<T>> Div for Number<T, P> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.div_with(rhs, DivByZero::Panic)
     }
 }
 
-impl HasSaturatingAdd for u32 {
-    fn do_saturating_add(&self, rhs: Self) -> Self {
-        self.saturating_add(rhs)
+impl<T: Div<Output = T>, P: DivPolicy<T>> DivAssign for Number<T, P> {
+    fn div_assign(&mut self, rhs: Self) {
+        let (value, overflow) = P::policy_div(&self.0, &rhs.0, DivByZero::Panic);
+        self.0 = value;
+        self.1 = self.1.merge(rhs.1, overflow, "divide");
     }
 }
 
-impl HasSaturatingSub for u32 {
-    fn do_saturating_sub(&self, rhs: Self) -> Self {
-        self.saturating_sub(rhs)
+impl<T: Rem<Output = T> + HasSaturatingDiv, P: OverflowPolicy> Rem for Number<T, P> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        self.rem_with(rhs, DivByZero::Panic)
     }
 }
 
-impl HasSaturatingMul for u32 {
-    fn do_saturating_mul(&self, rhs: Self) -> Self {
-        self.saturating_mul(rhs)
+impl<T: Rem<Output = T> + HasSaturatingDiv, P: OverflowPolicy> RemAssign for Number<T, P> {
+    fn rem_assign(&mut self, rhs: Self) {
+        self.0 = self.0.do_saturating_rem(&rhs.0, DivByZero::Panic);
+        self.1 = self
+            .1
+            .merge(rhs.1, Saturation::None, "calculate the remainder");
     }
 }
 
-pub type SaturatingU32 = SaturatingNumber<u32>;
+impl<T, P: DivPolicy<T>> Number<T, P> {
+    /// Divides by `rhs`, handling a zero divisor according to `on_zero`. The
+    /// `/` and `/=` operators always behave like `DivByZero::Panic`.
+    pub fn div_with(self, rhs: Self, on_zero: DivByZero) -> Self {
+        let (value, overflow) = P::policy_div(&self.0, &rhs.0, on_zero);
+        Self(value, self.1.merge(rhs.1, overflow, "divide"))
+    }
+}
 
-impl HasSaturatingAdd for u64 {
-    fn do_saturating_add(&self, rhs: Self) -> Self {
-        self.saturating_add(rhs)
+impl<T: HasSaturatingDiv, P: OverflowPolicy> Number<T, P> {
+    /// Computes the remainder of dividing by `rhs`, handling a zero divisor
+    /// according to `on_zero`. The `%` and `%=` operators always behave like
+    /// `DivByZero::Panic`.
+    pub fn rem_with(self, rhs: Self, on_zero: DivByZero) -> Self {
+        let value = self.0.do_saturating_rem(&rhs.0, on_zero);
+        Self(
+            value,
+            self.1
+                .merge(rhs.1, Saturation::None, "calculate the remainder"),
+        )
     }
 }
 
-impl HasSaturatingSub for u64 {
-    fn do_saturating_sub(&self, rhs: Self) -> Self {
-        self.saturating_sub(rhs)
+impl<T: Neg<Output = T>, P: NegPolicy<T>> Neg for Number<T, P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        let (value, overflow) = P::policy_neg(&self.0);
+        Self(value, self.1.merge(P::default(), overflow, "negate"))
     }
 }
 
-impl HasSaturatingMul for u64 {
-    fn do_saturating_mul(&self, rhs: Self) -> Self {
-        self.saturating_mul(rhs)
+impl<T, P: AbsPolicy<T>> Number<T, P> {
+    /// Returns the absolute value. Under `Saturate`, `MIN` becomes `MAX`
+    /// instead of panicking.
+    pub fn abs(self) -> Self {
+        let (value, overflow) = P::policy_abs(&self.0);
+        Self(value, self.1.merge(P::default(), overflow, "negate"))
     }
 }
 
-pub type SaturatingU64 = SaturatingNumber<u64>;
+impl<T: fmt::Debug, P: OverflowPolicy> fmt::Debug for Number<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.0)?;
+        if self.1.is_poisoned() {
+            f.write_str(" (poisoned)")?;
+        }
+        Ok(())
+    }
+}
 
-impl HasSaturatingAdd for u128 {
-    fn do_saturating_add(&self, rhs: Self) -> Self {
-        self.saturating_add(rhs)
+/// The alternate form (`{:#}`) appends ` (saturated)` to values sitting at a
+/// saturation bound. The width then covers the number and the marker together.
+impl<T: fmt::Display + HasSaturatingBounds> fmt::Display for SaturatingNumber<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() && self.0.is_at_bound() {
+            fmt_marked(&self.0, " (saturated)", f)
+        } else {
+            fmt::Display::fmt(&self.0, f)
+        }
     }
 }
 
-impl HasSaturatingSub for u128 {
-    fn do_saturating_sub(&self, rhs: Self) -> Self {
-        self.saturating_sub(rhs)
+/// Writes `value` followed by `marker`, padding both together according to
+/// the width, fill and alignment of `f`. Zero-padding pads with the fill
+/// character instead.
+pub(crate) fn fmt_marked<T: fmt::Display>(
+    value: &T,
+    marker: &str,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    struct Counter(usize);
+
+    impl fmt::Write for Counter {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 += s.chars().count();
+            Ok(())
+        }
+    }
+
+    let plus = f.sign_plus();
+    let write_value = |out: &mut dyn fmt::Write| {
+        if plus {
+            write!(out, "{:+}{}", value, marker)
+        } else {
+            write!(out, "{}{}", value, marker)
+        }
+    };
+    let mut len = Counter(0);
+    write_value(&mut len)?;
+    let padding = f.width().map_or(0, |width| width.saturating_sub(len.0));
+    // Numbers are right-aligned by default.
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        fmt::Write::write_char(f, fill)?;
     }
+    write_value(f)?;
+    for _ in 0..after {
+        fmt::Write::write_char(f, fill)?;
+    }
+    Ok(())
+}
+
+macro_rules! impl_fmt_passthrough {
+    ($($Trait:ident),*) => {
+        $(
+            impl<T: fmt::$Trait, P> fmt::$Trait for Number<T, P> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::$Trait::fmt(&self.0, f)
+                }
+            }
+        )*
+    };
 }
 
-impl HasSaturatingMul for u128 {
-    fn do_saturating_mul(&self, rhs: Self) -> Self {
-        self.saturating_mul(rhs)
+impl_fmt_passthrough!(LowerHex, UpperHex, Octal, Binary, LowerExp, UpperExp);
+
+/// Returns which bound a primitive saturating operation clamped to. On
+/// overflow the saturated result is exactly one of the bounds, which tells us
+/// the direction.
+fn direction(overflowed: bool, at_max: bool) -> Saturation {
+    match (overflowed, at_max) {
+        (false, _) => Saturation::None,
+        (true, true) => Saturation::Upper,
+        (true, false) => Saturation::Lower,
     }
 }
 
+macro_rules! impl_has_saturating_arith {
+    ($($t:ty),*) => {
+        $(
+            impl HasSaturatingAdd for $t {
+                fn do_saturating_add(&self, rhs: &Self) -> Self {
+                    self.saturating_add(*rhs)
+                }
+
+                fn do_saturating_add_reporting(&self, rhs: &Self) -> (Self, Saturation) {
+                    let (_, overflowed) = self.overflowing_add(*rhs);
+                    let result = self.saturating_add(*rhs);
+                    (result, direction(overflowed, result == <$t>::MAX))
+                }
+
+                fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(self, f)
+                }
+            }
+
+            impl HasSaturatingSub for $t {
+                fn do_saturating_sub(&self, rhs: &Self) -> Self {
+                    self.saturating_sub(*rhs)
+                }
+
+                fn do_saturating_sub_reporting(&self, rhs: &Self) -> (Self, Saturation) {
+                    let (_, overflowed) = self.overflowing_sub(*rhs);
+                    let result = self.saturating_sub(*rhs);
+                    (result, direction(overflowed, result == <$t>::MAX))
+                }
+
+                fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(self, f)
+                }
+            }
+
+            impl HasSaturatingMul for $t {
+                fn do_saturating_mul(&self, rhs: &Self) -> Self {
+                    self.saturating_mul(*rhs)
+                }
+
+                fn do_saturating_mul_reporting(&self, rhs: &Self) -> (Self, Saturation) {
+                    let (_, overflowed) = self.overflowing_mul(*rhs);
+                    let result = self.saturating_mul(*rhs);
+                    (result, direction(overflowed, result == <$t>::MAX))
+                }
+
+                fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(self, f)
+                }
+            }
+
+            impl HasSaturatingPow for $t {
+                fn do_saturating_pow(&self, exp: u32) -> Self {
+                    self.saturating_pow(exp)
+                }
+
+                fn do_saturating_pow_reporting(&self, exp: u32) -> (Self, Saturation) {
+                    let overflowed = self.checked_pow(exp).is_none();
+                    let result = self.saturating_pow(exp);
+                    (result, direction(overflowed, result == <$t>::MAX))
+                }
+
+                fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(self, f)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_has_wrapping_arith {
+    ($($t:ty),*) => {
+        $(
+            impl HasWrappingAdd for $t {
+                fn do_wrapping_add(&self, rhs: &Self) -> Self {
+                    self.wrapping_add(*rhs)
+                }
+            }
+
+            impl HasWrappingSub for $t {
+                fn do_wrapping_sub(&self, rhs: &Self) -> Self {
+                    self.wrapping_sub(*rhs)
+                }
+            }
+
+            impl HasWrappingMul for $t {
+                fn do_wrapping_mul(&self, rhs: &Self) -> Self {
+                    self.wrapping_mul(*rhs)
+                }
+            }
+
+            impl HasWrappingPow for $t {
+                fn do_wrapping_pow(&self, exp: u32) -> Self {
+                    self.wrapping_pow(exp)
+                }
+            }
+
+            impl HasWrappingShl for $t {
+                fn do_wrapping_shl(&self, rhs: u32) -> Self {
+                    self.checked_shl(rhs).unwrap_or(0)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_has_saturating_fold {
+    (@impl $t:ty, |$x:ident| $absorbs_add:expr) => {
+        impl HasSaturatingSum for $t {
+            fn zero() -> Self {
+                0
+            }
+
+            fn absorbs_add(&self) -> bool {
+                let $x = *self;
+                $absorbs_add
+            }
+        }
+
+        impl HasSaturatingProduct for $t {
+            fn one() -> Self {
+                1
+            }
+
+            fn absorbs_mul(&self) -> bool {
+                *self == 0
+            }
+        }
+    };
+    // An unsigned sum at `MAX` can never decrease again.
+    (unsigned: $($t:ty),*) => {
+        $(impl_has_saturating_fold!(@impl $t, |x| x == <$t>::MAX);)*
+    };
+    // A signed sum can always be pulled back by a negative term.
+    (signed: $($t:ty),*) => {
+        $(impl_has_saturating_fold!(@impl $t, |_x| false);)*
+    };
+}
+
+macro_rules! impl_has_saturating_bounds {
+    (unsigned: $($t:ty),*) => {
+        $(
+            impl HasSaturatingBounds for $t {
+                fn is_at_bound(&self) -> bool {
+                    *self == <$t>::MAX
+                }
+            }
+        )*
+    };
+    (signed: $($t:ty),*) => {
+        $(
+            impl HasSaturatingBounds for $t {
+                fn is_at_bound(&self) -> bool {
+                    *self == <$t>::MAX || *self == <$t>::MIN
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_has_saturating_shift {
+    (@impl $t:ty, |$x:ident| $saturated:expr) => {
+        impl HasSaturatingShl for $t {
+            fn do_saturating_shl(&self, rhs: u32) -> Self {
+                self.do_saturating_shl_reporting(rhs).0
+            }
+
+            fn do_saturating_shl_reporting(&self, rhs: u32) -> (Self, Saturation) {
+                let $x = *self;
+                if $x == 0 {
+                    return (0, Saturation::None);
+                }
+                // Shifting back recovers the input only if no set bits (and,
+                // for signed types, not the sign) were shifted out.
+                if rhs < <$t>::BITS && ($x << rhs) >> rhs == $x {
+                    return ($x << rhs, Saturation::None);
+                }
+                let saturated = $saturated;
+                (saturated, direction(true, saturated == <$t>::MAX))
+            }
+
+            fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, f)
+            }
+        }
+
+        impl HasSaturatingShr for $t {
+            fn do_saturating_shr(&self, rhs: u32) -> Self {
+                // Oversized shifts give what shifting out every bit would:
+                // zero, or -1 for negative signed values.
+                self.checked_shr(rhs)
+                    .unwrap_or((*self >> (<$t>::BITS - 1)) >> 1)
+            }
+        }
+    };
+    (unsigned: $($t:ty),*) => {
+        $(impl_has_saturating_shift!(@impl $t, |_x| <$t>::MAX);)*
+    };
+    (signed: $($t:ty),*) => {
+        $(impl_has_saturating_shift!(@impl $t, |x| if x < 0 { <$t>::MIN } else { <$t>::MAX });)*
+    };
+}
+
+macro_rules! impl_has_saturating_div {
+    (@impl $t:ty, |$x:ident| $saturated:expr) => {
+        impl HasSaturatingDiv for $t {
+            fn do_saturating_div(&self, rhs: &Self, on_zero: DivByZero) -> Self {
+                let rhs = *rhs;
+                if rhs == 0 {
+                    match on_zero {
+                        DivByZero::Panic => {}
+                        DivByZero::Saturate => {
+                            let $x = *self;
+                            return $saturated;
+                        }
+                        DivByZero::Zero => return 0,
+                    }
+                }
+                // `saturating_div` clamps signed `MIN / -1` to `MAX`.
+                self.saturating_div(rhs)
+            }
+
+            fn do_saturating_rem(&self, rhs: &Self, on_zero: DivByZero) -> Self {
+                let rhs = *rhs;
+                if rhs == 0 {
+                    match on_zero {
+                        DivByZero::Panic => {}
+                        DivByZero::Saturate => return *self,
+                        DivByZero::Zero => return 0,
+                    }
+                }
+                // `wrapping_rem` yields 0 for signed `MIN % -1` instead of
+                // panicking.
+                self.wrapping_rem(rhs)
+            }
+
+            fn do_saturating_div_reporting(
+                &self,
+                rhs: &Self,
+                on_zero: DivByZero,
+            ) -> (Self, Saturation) {
+                let result = self.do_saturating_div(rhs, on_zero);
+                let overflowed = match self.checked_div(*rhs) {
+                    Some(_) => false,
+                    None => *rhs != 0 || on_zero == DivByZero::Saturate,
+                };
+                (result, direction(overflowed, result == <$t>::MAX))
+            }
+
+            fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, f)
+            }
+        }
+
+        impl HasWrappingDiv for $t {
+            fn do_wrapping_div(&self, rhs: &Self, on_zero: DivByZero) -> Self {
+                if *rhs == 0 {
+                    self.do_saturating_div(rhs, on_zero)
+                } else {
+                    self.wrapping_div(*rhs)
+                }
+            }
+        }
+    };
+    (unsigned: $($t:ty),*) => {
+        $(impl_has_saturating_div!(@impl $t, |_x| <$t>::MAX);)*
+    };
+    (signed: $($t:ty),*) => {
+        $(impl_has_saturating_div!(@impl $t, |x| if x < 0 { <$t>::MIN } else { <$t>::MAX });)*
+    };
+}
+
+macro_rules! impl_saturating_from {
+    ($($dst:ty),*) => {
+        $(impl_saturating_from!(@to $dst: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);)*
+    };
+    (@to $dst:ty: $($src:ty),*) => {
+        $(
+            impl SaturatingFrom<$src> for $dst {
+                fn saturating_from(value: $src) -> Self {
+                    match <$dst>::try_from(value) {
+                        Ok(converted) => converted,
+                        // Out of range values are either above `MAX` or below
+                        // `MIN`, and only the latter can be non-positive.
+                        Err(_) if value > 0 => <$dst>::MAX,
+                        Err(_) => <$dst>::MIN,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+// Coherence rules prevent a blanket `impl<T, P> Add<Number<T, P>> for T`,
+// so the primitive-on-the-left forms are implemented per type.
+macro_rules! impl_primitive_lhs_ops {
+    ($($t:ty),*) => {
+        $(
+            impl<P: AddPolicy<$t>> Add<Number<$t, P>> for $t {
+                type Output = Number<$t, P>;
+
+                fn add(self, rhs: Number<$t, P>) -> Number<$t, P> {
+                    let (value, overflow) = P::policy_add(&self, &rhs.0);
+                    Number(value, P::default().merge(rhs.1, overflow, "add"))
+                }
+            }
+
+            impl<P: SubPolicy<$t>> Sub<Number<$t, P>> for $t {
+                type Output = Number<$t, P>;
+
+                fn sub(self, rhs: Number<$t, P>) -> Number<$t, P> {
+                    let (value, overflow) = P::policy_sub(&self, &rhs.0);
+                    Number(value, P::default().merge(rhs.1, overflow, "subtract"))
+                }
+            }
+
+            impl<P: MulPolicy<$t>> Mul<Number<$t, P>> for $t {
+                type Output = Number<$t, P>;
+
+                fn mul(self, rhs: Number<$t, P>) -> Number<$t, P> {
+                    let (value, overflow) = P::policy_mul(&self, &rhs.0);
+                    Number(value, P::default().merge(rhs.1, overflow, "multiply"))
+                }
+            }
+
+            impl<P: OverflowPolicy> PartialEq<Number<$t, P>> for $t {
+                fn eq(&self, other: &Number<$t, P>) -> bool {
+                    other == self
+                }
+            }
+
+            impl<P: OverflowPolicy> PartialOrd<Number<$t, P>> for $t {
+                fn partial_cmp(&self, other: &Number<$t, P>) -> Option<Ordering> {
+                    other.partial_cmp(self).map(Ordering::reverse)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_has_saturating_signed {
+    ($($t:ty),*) => {
+        $(
+            // Only `MIN` overflows, and its negation and absolute value are
+            // clamped to `MAX`.
+
+            impl HasSaturatingNeg for $t {
+                fn do_saturating_neg(&self) -> Self {
+                    self.saturating_neg()
+                }
+
+                fn do_saturating_neg_reporting(&self) -> (Self, Saturation) {
+                    let result = self.saturating_neg();
+                    (result, direction(*self == <$t>::MIN, true))
+                }
+
+                fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(self, f)
+                }
+            }
+
+            impl HasSaturatingAbs for $t {
+                fn do_saturating_abs(&self) -> Self {
+                    self.saturating_abs()
+                }
+
+                fn do_saturating_abs_reporting(&self) -> (Self, Saturation) {
+                    let result = self.saturating_abs();
+                    (result, direction(*self == <$t>::MIN, true))
+                }
+
+                fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(self, f)
+                }
+            }
+
+            impl HasWrappingNeg for $t {
+                fn do_wrapping_neg(&self) -> Self {
+                    self.wrapping_neg()
+                }
+            }
+
+            impl HasWrappingAbs for $t {
+                fn do_wrapping_abs(&self) -> Self {
+                    self.wrapping_abs()
+                }
+            }
+        )*
+    };
+}
+
+// `From` and the operator traits cannot be called in const context, so each
+// primitive also gets inherent `const fn` equivalents for building constants.
+macro_rules! impl_const_fns {
+    ($($t:ty),*) => {
+        $(
+            impl SaturatingNumber<$t> {
+                pub const MIN: Self = Self(<$t>::MIN, Saturate);
+                pub const MAX: Self = Self(<$t>::MAX, Saturate);
+                pub const ZERO: Self = Self(0, Saturate);
+                pub const ONE: Self = Self(1, Saturate);
+
+                pub const fn new(value: $t) -> Self {
+                    Self(value, Saturate)
+                }
+
+                pub const fn get(self) -> $t {
+                    self.0
+                }
+
+                pub const fn add(self, rhs: Self) -> Self {
+                    Self(self.0.saturating_add(rhs.0), Saturate)
+                }
+
+                pub const fn sub(self, rhs: Self) -> Self {
+                    Self(self.0.saturating_sub(rhs.0), Saturate)
+                }
+
+                pub const fn mul(self, rhs: Self) -> Self {
+                    Self(self.0.saturating_mul(rhs.0), Saturate)
+                }
+            }
+        )*
+    };
+}
+
+impl_has_saturating_arith!(u8, u16, u32, u64, u128, usize);
+impl_has_saturating_arith!(i8, i16, i32, i64, i128, isize);
+impl_has_wrapping_arith!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_has_saturating_fold!(unsigned: u8, u16, u32, u64, u128, usize);
+impl_has_saturating_fold!(signed: i8, i16, i32, i64, i128, isize);
+impl_has_saturating_bounds!(unsigned: u8, u16, u32, u64, u128, usize);
+impl_has_saturating_bounds!(signed: i8, i16, i32, i64, i128, isize);
+impl_has_saturating_shift!(unsigned: u8, u16, u32, u64, u128, usize);
+impl_has_saturating_shift!(signed: i8, i16, i32, i64, i128, isize);
+impl_has_saturating_div!(unsigned: u8, u16, u32, u64, u128, usize);
+impl_has_saturating_div!(signed: i8, i16, i32, i64, i128, isize);
+impl_has_saturating_signed!(i8, i16, i32, i64, i128, isize);
+impl_saturating_from!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_primitive_lhs_ops!(u8, u16, u32, u64, u128, usize);
+impl_primitive_lhs_ops!(i8, i16, i32, i64, i128, isize);
+impl_const_fns!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+pub type SaturatingU8 = SaturatingNumber<u8>;
+pub type SaturatingU16 = SaturatingNumber<u16>;
+pub type SaturatingU32 = SaturatingNumber<u32>;
+pub type SaturatingU64 = SaturatingNumber<u64>;
 pub type SaturatingU128 = SaturatingNumber<u128>;
+pub type SaturatingUsize = SaturatingNumber<usize>;
+
+pub type SaturatingI8 = SaturatingNumber<i8>;
+pub type SaturatingI16 = SaturatingNumber<i16>;
+pub type SaturatingI32 = SaturatingNumber<i32>;
+pub type SaturatingI64 = SaturatingNumber<i64>;
+pub type SaturatingI128 = SaturatingNumber<i128>;
+pub type SaturatingIsize = SaturatingNumber<isize>;
+
+pub type SaturatingU256 = SaturatingNumber<U256>;
+pub type SaturatingU512 = SaturatingNumber<U512>;
+
+pub type SaturatingUint<const BITS: u32> = SaturatingNumber<Uint<BITS>>;
+pub type SaturatingU24 = SaturatingUint<24>;
+pub type SaturatingU48 = SaturatingUint<48>;
 
 #[cfg(test)]
 mod test {
@@ -148,16 +1299,16 @@ mod test {
             SaturatingU64::from(0)
         );
         assert_eq!(
-            SaturatingU64::from(0) + SaturatingU64::from(std::u64::MAX),
-            SaturatingU64::from(std::u64::MAX)
+            SaturatingU64::from(0) + SaturatingU64::from(u64::MAX),
+            SaturatingU64::from(u64::MAX)
         );
         assert_eq!(
-            SaturatingU64::from(std::u64::MAX) + SaturatingU64::from(std::u64::MAX),
-            SaturatingU64::from(std::u64::MAX)
+            SaturatingU64::from(u64::MAX) + SaturatingU64::from(u64::MAX),
+            SaturatingU64::from(u64::MAX)
         );
         assert_eq!(
-            SaturatingU64::from(std::u64::MAX) + SaturatingU64::from(10),
-            SaturatingU64::from(std::u64::MAX)
+            SaturatingU64::from(u64::MAX) + SaturatingU64::from(10),
+            SaturatingU64::from(u64::MAX)
         );
     }
 
@@ -168,16 +1319,16 @@ mod test {
             SaturatingU64::from(0)
         );
         assert_eq!(
-            SaturatingU64::from(0) - SaturatingU64::from(std::u64::MAX),
+            SaturatingU64::from(0) - SaturatingU64::from(u64::MAX),
             SaturatingU64::from(0)
         );
         assert_eq!(
-            SaturatingU64::from(std::u64::MAX) - SaturatingU64::from(std::u64::MAX),
+            SaturatingU64::from(u64::MAX) - SaturatingU64::from(u64::MAX),
             SaturatingU64::from(0)
         );
         assert_eq!(
-            SaturatingU64::from(std::u64::MAX) - SaturatingU64::from(10),
-            SaturatingU64::from(std::u64::MAX - 10)
+            SaturatingU64::from(u64::MAX) - SaturatingU64::from(10),
+            SaturatingU64::from(u64::MAX - 10)
         );
         assert_eq!(
             SaturatingU64::from(0) - SaturatingU64::from(10),
@@ -196,16 +1347,653 @@ mod test {
             SaturatingU64::from(0)
         );
         assert_eq!(
-            SaturatingU64::from(0) * SaturatingU64::from(std::u64::MAX),
+            SaturatingU64::from(0) * SaturatingU64::from(u64::MAX),
             SaturatingU64::from(0)
         );
         assert_eq!(
-            SaturatingU64::from(std::u64::MAX) * SaturatingU64::from(std::u64::MAX),
-            SaturatingU64::from(std::u64::MAX)
+            SaturatingU64::from(u64::MAX) * SaturatingU64::from(u64::MAX),
+            SaturatingU64::from(u64::MAX)
+        );
+        assert_eq!(
+            SaturatingU64::from(u64::MAX) * SaturatingU64::from(10),
+            SaturatingU64::from(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_signed_addition() {
+        assert_eq!(
+            SaturatingI64::from(-5) + SaturatingI64::from(3),
+            SaturatingI64::from(-2)
+        );
+        assert_eq!(
+            SaturatingI64::from(i64::MAX) + SaturatingI64::from(1),
+            SaturatingI64::from(i64::MAX)
+        );
+        assert_eq!(
+            SaturatingI64::from(i64::MIN) + SaturatingI64::from(-1),
+            SaturatingI64::from(i64::MIN)
+        );
+        assert_eq!(
+            SaturatingI8::from(i8::MIN) + SaturatingI8::from(i8::MIN),
+            SaturatingI8::from(i8::MIN)
+        );
+        let mut x = SaturatingIsize::from(isize::MAX - 1);
+        x += SaturatingIsize::from(10);
+        assert_eq!(x, SaturatingIsize::from(isize::MAX));
+    }
+
+    #[test]
+    fn test_signed_subtraction() {
+        assert_eq!(
+            SaturatingI64::from(0) - SaturatingI64::from(10),
+            SaturatingI64::from(-10)
+        );
+        assert_eq!(
+            SaturatingI64::from(i64::MIN) - SaturatingI64::from(1),
+            SaturatingI64::from(i64::MIN)
+        );
+        assert_eq!(
+            SaturatingI64::from(i64::MAX) - SaturatingI64::from(-1),
+            SaturatingI64::from(i64::MAX)
+        );
+        assert_eq!(
+            SaturatingI16::from(0) - SaturatingI16::from(i16::MIN),
+            SaturatingI16::from(i16::MAX)
+        );
+    }
+
+    #[test]
+    fn test_signed_multiplication() {
+        assert_eq!(
+            SaturatingI32::from(-3) * SaturatingI32::from(7),
+            SaturatingI32::from(-21)
+        );
+        assert_eq!(
+            SaturatingI32::from(i32::MAX) * SaturatingI32::from(-2),
+            SaturatingI32::from(i32::MIN)
+        );
+        assert_eq!(
+            SaturatingI32::from(i32::MIN) * SaturatingI32::from(-1),
+            SaturatingI32::from(i32::MAX)
+        );
+        assert_eq!(
+            SaturatingI128::from(i128::MIN) * SaturatingI128::from(i128::MIN),
+            SaturatingI128::from(i128::MAX)
+        );
+    }
+
+    #[test]
+    fn test_negation() {
+        assert_eq!(-SaturatingI64::from(5), SaturatingI64::from(-5));
+        assert_eq!(-SaturatingI64::from(0), SaturatingI64::from(0));
+        assert_eq!(
+            -SaturatingI64::from(i64::MAX),
+            SaturatingI64::from(i64::MIN + 1)
+        );
+        assert_eq!(
+            -SaturatingI64::from(i64::MIN),
+            SaturatingI64::from(i64::MAX)
+        );
+        assert_eq!(-SaturatingI8::from(i8::MIN), SaturatingI8::from(i8::MAX));
+    }
+
+    #[test]
+    fn test_abs() {
+        assert_eq!(SaturatingI32::from(-7).abs(), SaturatingI32::from(7));
+        assert_eq!(SaturatingI32::from(7).abs(), SaturatingI32::from(7));
+        assert_eq!(
+            SaturatingI32::from(i32::MIN).abs(),
+            SaturatingI32::from(i32::MAX)
+        );
+        assert_eq!(
+            SaturatingIsize::from(isize::MIN).abs(),
+            SaturatingIsize::from(isize::MAX)
+        );
+    }
+
+    #[test]
+    fn test_u8_exhaustive() {
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                let (x, y) = (SaturatingU8::from(a), SaturatingU8::from(b));
+                assert_eq!(x + y, SaturatingU8::from(a.saturating_add(b)));
+                assert_eq!(x - y, SaturatingU8::from(a.saturating_sub(b)));
+                assert_eq!(x * y, SaturatingU8::from(a.saturating_mul(b)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_u16_against_std() {
+        // Every left operand is checked; the right operand is strided to keep
+        // the test fast in debug builds while still covering both edges.
+        let rhs: Vec<u16> = (0..=u16::MAX)
+            .step_by(251)
+            .chain([1, 2, u16::MAX - 1, u16::MAX].iter().copied())
+            .collect();
+        for a in 0..=u16::MAX {
+            for &b in &rhs {
+                let (x, y) = (SaturatingU16::from(a), SaturatingU16::from(b));
+                assert_eq!(x + y, SaturatingU16::from(a.saturating_add(b)));
+                assert_eq!(x - y, SaturatingU16::from(a.saturating_sub(b)));
+                assert_eq!(x * y, SaturatingU16::from(a.saturating_mul(b)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_usize() {
+        assert_eq!(
+            SaturatingUsize::from(usize::MAX) + SaturatingUsize::from(1),
+            SaturatingUsize::from(usize::MAX)
+        );
+        assert_eq!(
+            SaturatingUsize::from(3) - SaturatingUsize::from(4),
+            SaturatingUsize::from(0)
+        );
+        assert_eq!(
+            SaturatingUsize::from(usize::MAX / 2) * SaturatingUsize::from(3),
+            SaturatingUsize::from(usize::MAX)
+        );
+    }
+
+    #[test]
+    fn test_division() {
+        assert_eq!(
+            SaturatingU64::from(10) / SaturatingU64::from(3),
+            SaturatingU64::from(3)
+        );
+        assert_eq!(
+            SaturatingU64::from(u64::MAX) / SaturatingU64::from(1),
+            SaturatingU64::from(u64::MAX)
+        );
+        assert_eq!(
+            SaturatingI32::from(-10) / SaturatingI32::from(3),
+            SaturatingI32::from(-3)
+        );
+        assert_eq!(
+            SaturatingI32::from(i32::MIN) / SaturatingI32::from(-1),
+            SaturatingI32::from(i32::MAX)
+        );
+        let mut x = SaturatingI8::from(i8::MIN);
+        x /= SaturatingI8::from(-1);
+        assert_eq!(x, SaturatingI8::from(i8::MAX));
+    }
+
+    #[test]
+    fn test_remainder() {
+        assert_eq!(
+            SaturatingU64::from(10) % SaturatingU64::from(3),
+            SaturatingU64::from(1)
+        );
+        assert_eq!(
+            SaturatingI32::from(-10) % SaturatingI32::from(3),
+            SaturatingI32::from(-1)
+        );
+        assert_eq!(
+            SaturatingI32::from(i32::MIN) % SaturatingI32::from(-1),
+            SaturatingI32::from(0)
+        );
+        let mut x = SaturatingU8::from(200);
+        x %= SaturatingU8::from(7);
+        assert_eq!(x, SaturatingU8::from(4));
+    }
+
+    #[test]
+    fn test_division_by_zero_policy() {
+        let zero = SaturatingU32::from(0);
+        assert_eq!(
+            SaturatingU32::from(5).div_with(zero, DivByZero::Saturate),
+            SaturatingU32::from(u32::MAX)
+        );
+        assert_eq!(SaturatingU32::from(5).div_with(zero, DivByZero::Zero), zero);
+        assert_eq!(
+            SaturatingU32::from(5).rem_with(zero, DivByZero::Saturate),
+            SaturatingU32::from(5)
+        );
+        assert_eq!(SaturatingU32::from(5).rem_with(zero, DivByZero::Zero), zero);
+        assert_eq!(
+            SaturatingI16::from(-5).div_with(SaturatingI16::from(0), DivByZero::Saturate),
+            SaturatingI16::from(i16::MIN)
+        );
+        assert_eq!(
+            SaturatingI16::from(5).div_with(SaturatingI16::from(0), DivByZero::Saturate),
+            SaturatingI16::from(i16::MAX)
+        );
+        assert_eq!(
+            SaturatingI16::from(9).div_with(SaturatingI16::from(2), DivByZero::Zero),
+            SaturatingI16::from(4)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_division_by_zero_panics() {
+        let _ = SaturatingU64::from(1) / SaturatingU64::from(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_remainder_by_zero_panics() {
+        let _ = SaturatingI64::from(1) % SaturatingI64::from(0);
+    }
+
+    #[test]
+    fn test_pow() {
+        assert_eq!(SaturatingU64::from(2).pow(10), SaturatingU64::from(1024));
+        assert_eq!(
+            SaturatingU64::from(2).pow(64),
+            SaturatingU64::from(u64::MAX)
+        );
+        assert_eq!(SaturatingU64::from(0).pow(0), SaturatingU64::from(1));
+        assert_eq!(SaturatingI32::from(-2).pow(3), SaturatingI32::from(-8));
+        assert_eq!(
+            SaturatingI32::from(-2).pow(33),
+            SaturatingI32::from(i32::MIN)
+        );
+        assert_eq!(
+            SaturatingI32::from(-2).pow(32),
+            SaturatingI32::from(i32::MAX)
+        );
+    }
+
+    #[test]
+    fn test_shift_left() {
+        assert_eq!(SaturatingU64::from(1) << 10, SaturatingU64::from(1024));
+        assert_eq!(SaturatingU64::from(1) << 63, SaturatingU64::from(1 << 63));
+        assert_eq!(SaturatingU64::from(1) << 64, SaturatingU64::from(u64::MAX));
+        assert_eq!(SaturatingU64::from(3) << 63, SaturatingU64::from(u64::MAX));
+        assert_eq!(SaturatingU64::from(0) << 200, SaturatingU64::from(0));
+        assert_eq!(SaturatingU8::from(0x81) << 1, SaturatingU8::from(u8::MAX));
+        assert_eq!(SaturatingI8::from(1) << 6, SaturatingI8::from(64));
+        assert_eq!(SaturatingI8::from(1) << 7, SaturatingI8::from(i8::MAX));
+        assert_eq!(SaturatingI8::from(-1) << 7, SaturatingI8::from(i8::MIN));
+        assert_eq!(SaturatingI8::from(-2) << 7, SaturatingI8::from(i8::MIN));
+        assert_eq!(SaturatingI8::from(-3) << 100, SaturatingI8::from(i8::MIN));
+        let mut x = SaturatingU32::from(5);
+        x <<= 2;
+        assert_eq!(x, SaturatingU32::from(20));
+    }
+
+    #[test]
+    fn test_shift_right() {
+        assert_eq!(SaturatingU64::from(1024) >> 10, SaturatingU64::from(1));
+        assert_eq!(SaturatingU64::from(u64::MAX) >> 64, SaturatingU64::from(0));
+        assert_eq!(
+            SaturatingU64::from(u64::MAX) >> 1000,
+            SaturatingU64::from(0)
+        );
+        assert_eq!(SaturatingI8::from(-128) >> 3, SaturatingI8::from(-16));
+        assert_eq!(SaturatingI8::from(-128) >> 8, SaturatingI8::from(-1));
+        assert_eq!(SaturatingI8::from(127) >> 8, SaturatingI8::from(0));
+        let mut x = SaturatingU32::from(20);
+        x >>= 2;
+        assert_eq!(x, SaturatingU32::from(5));
+    }
+
+    #[test]
+    fn test_u8_shift_against_reference() {
+        for a in 0..=u8::MAX {
+            for n in 0..16 {
+                let wide = u32::from(a) << n;
+                let expected = if wide > u32::from(u8::MAX) {
+                    u8::MAX
+                } else {
+                    wide as u8
+                };
+                assert_eq!(SaturatingU8::from(a) << n, SaturatingU8::from(expected));
+            }
+        }
+    }
+
+    #[test]
+    fn test_mixed_operands() {
+        assert_eq!(SaturatingU64::from(5) + 1, SaturatingU64::from(6));
+        assert_eq!(
+            SaturatingU64::from(u64::MAX) + 1,
+            SaturatingU64::from(u64::MAX)
+        );
+        assert_eq!(SaturatingU64::from(5) - 10, SaturatingU64::from(0));
+        assert_eq!(
+            SaturatingU64::from(u64::MAX) * 2,
+            SaturatingU64::from(u64::MAX)
+        );
+        assert_eq!(
+            1 + SaturatingU64::from(u64::MAX),
+            SaturatingU64::from(u64::MAX)
+        );
+        assert_eq!(5 - SaturatingU64::from(10), SaturatingU64::from(0));
+        assert_eq!(3 * SaturatingI8::from(-100), SaturatingI8::from(i8::MIN));
+
+        let mut x = SaturatingU32::from(10);
+        x += 5;
+        assert_eq!(x, SaturatingU32::from(15));
+        x -= 20;
+        assert_eq!(x, SaturatingU32::from(0));
+        x += u32::MAX;
+        x *= 2;
+        assert_eq!(x, SaturatingU32::from(u32::MAX));
+    }
+
+    #[test]
+    fn test_mixed_comparisons() {
+        assert_eq!(SaturatingU64::from(5), 5);
+        assert_eq!(5, SaturatingU64::from(5));
+        assert!(SaturatingU64::from(5) != 6);
+        assert!(SaturatingU64::from(5) < 6);
+        assert!(SaturatingU64::from(5) >= 5);
+        assert!(7 > SaturatingU64::from(5));
+        assert!(SaturatingI32::from(-1) < 0);
+        assert!(SaturatingU8::from(200) + 100 == u8::MAX);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_reference_operands() {
+        let a = SaturatingU64::from(u64::MAX - 1);
+        let b = SaturatingU64::from(5);
+        assert_eq!(&a + &b, SaturatingU64::from(u64::MAX));
+        assert_eq!(&a + b, SaturatingU64::from(u64::MAX));
+        assert_eq!(a + &b, SaturatingU64::from(u64::MAX));
+        assert_eq!(&b - &a, SaturatingU64::from(0));
+        assert_eq!(&a * &b, SaturatingU64::from(u64::MAX));
+        let mut c = b;
+        c += &b;
+        c -= &SaturatingU64::from(1);
+        c *= &b;
+        assert_eq!(c, SaturatingU64::from(45));
+    }
+
+    /// A deliberately non-`Copy`, non-`Clone` inner type that records whether
+    /// the in-place path was taken.
+    #[derive(Debug, PartialEq)]
+    struct Heap(Box<u8>, bool);
+
+    impl Add for Heap {
+        type Output = Heap;
+
+        fn add(self, rhs: Heap) -> Heap {
+            Heap(Box::new(self.0.saturating_add(*rhs.0)), false)
+        }
+    }
+
+    impl HasSaturatingAdd for Heap {
+        fn do_saturating_add(&self, rhs: &Self) -> Self {
+            Heap(Box::new(self.0.saturating_add(*rhs.0)), false)
+        }
+
+        fn do_saturating_add_reporting(&self, rhs: &Self) -> (Self, Saturation) {
+            let saturation = match self.0.checked_add(*rhs.0) {
+                Some(_) => Saturation::None,
+                None => Saturation::Upper,
+            };
+            (self.do_saturating_add(rhs), saturation)
+        }
+
+        fn do_saturating_add_assign(&mut self, rhs: &Self) {
+            *self.0 = self.0.saturating_add(*rhs.0);
+            self.1 = true;
+        }
+    }
+
+    #[test]
+    fn test_non_copy_inner_type() {
+        let a = SaturatingNumber::from(Heap(Box::new(200), false));
+        let b = SaturatingNumber::from(Heap(Box::new(100), false));
+        assert_eq!(&a + &b, SaturatingNumber::from(Heap(Box::new(255), false)));
+        assert_eq!(a + &b, SaturatingNumber::from(Heap(Box::new(255), true)));
+        let mut c = SaturatingNumber::from(Heap(Box::new(1), false));
+        c += b;
+        assert_eq!(c, SaturatingNumber::from(Heap(Box::new(101), true)));
+    }
+
+    #[test]
+    fn test_saturating_conversions() {
+        assert_eq!(u32::saturating_from(u128::MAX), u32::MAX);
+        assert_eq!(u32::saturating_from(7u128), 7);
+        assert_eq!(u64::saturating_from(-5i64), 0);
+        assert_eq!(u64::saturating_from(i64::MAX), i64::MAX as u64);
+        assert_eq!(i8::saturating_from(1000u64), i8::MAX);
+        assert_eq!(i16::saturating_from(i128::MIN), i16::MIN);
+        assert_eq!(i128::saturating_from(u128::MAX), i128::MAX);
+        assert_eq!(usize::saturating_from(-1isize), 0);
+        let narrowed: u8 = 300u16.saturating_into();
+        assert_eq!(narrowed, u8::MAX);
+        let widened: i64 = (-3i8).saturating_into();
+        assert_eq!(widened, -3);
+    }
+
+    #[test]
+    fn test_saturating_conversions_exhaustive_i16() {
+        for value in i16::MIN..=i16::MAX {
+            let wide = i32::from(value);
+            assert_eq!(
+                i32::from(u8::saturating_from(value)),
+                wide.clamp(0, i32::from(u8::MAX))
+            );
+            assert_eq!(
+                i32::from(i8::saturating_from(value)),
+                wide.clamp(i32::from(i8::MIN), i32::from(i8::MAX))
+            );
+            assert_eq!(i32::from(u16::saturating_from(value)), wide.max(0));
+        }
+    }
+
+    #[test]
+    fn test_saturating_wrapper_conversions() {
+        let total = SaturatingU128::from(u128::MAX);
+        let field: SaturatingU32 = total.saturating_into();
+        assert_eq!(field, SaturatingU32::from(u32::MAX));
+        let delta = SaturatingI64::from(-42);
+        assert_eq!(
+            SaturatingU64::saturating_from(delta),
+            SaturatingU64::from(0)
+        );
+        assert_eq!(
+            SaturatingI8::saturating_from(SaturatingU8::from(200)),
+            SaturatingI8::from(i8::MAX)
+        );
+    }
+
+    #[test]
+    fn test_reporting() {
+        let max = SaturatingU64::from(u64::MAX);
+        assert_eq!(
+            SaturatingU64::from(1).add_reporting(SaturatingU64::from(2)),
+            (SaturatingU64::from(3), Saturation::None)
+        );
+        assert_eq!(
+            max.add_reporting(SaturatingU64::from(1)),
+            (max, Saturation::Upper)
+        );
+        assert_eq!(
+            SaturatingU64::from(1).sub_reporting(SaturatingU64::from(2)),
+            (SaturatingU64::from(0), Saturation::Lower)
+        );
+        assert_eq!(
+            max.mul_reporting(SaturatingU64::from(2)),
+            (max, Saturation::Upper)
+        );
+        assert_eq!(
+            SaturatingI32::from(i32::MIN).add_reporting(SaturatingI32::from(-1)),
+            (SaturatingI32::from(i32::MIN), Saturation::Lower)
+        );
+        assert_eq!(
+            SaturatingI32::from(i32::MIN).sub_reporting(SaturatingI32::from(1)),
+            (SaturatingI32::from(i32::MIN), Saturation::Lower)
+        );
+        assert_eq!(
+            SaturatingI32::from(i32::MAX).sub_reporting(SaturatingI32::from(-1)),
+            (SaturatingI32::from(i32::MAX), Saturation::Upper)
+        );
+        assert_eq!(
+            SaturatingI32::from(i32::MIN).mul_reporting(SaturatingI32::from(-1)),
+            (SaturatingI32::from(i32::MAX), Saturation::Upper)
+        );
+        assert_eq!(
+            SaturatingI32::from(i32::MAX).mul_reporting(SaturatingI32::from(-2)),
+            (SaturatingI32::from(i32::MIN), Saturation::Lower)
+        );
+        // Landing exactly on a bound is not saturation.
+        assert_eq!(
+            SaturatingI32::from(i32::MAX - 1).add_reporting(SaturatingI32::from(1)),
+            (SaturatingI32::from(i32::MAX), Saturation::None)
+        );
+    }
+
+    #[test]
+    fn test_i8_reporting_against_reference() {
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let (x, y) = (SaturatingI8::from(a), SaturatingI8::from(b));
+                let (wa, wb) = (i32::from(a), i32::from(b));
+                let expected = |wide: i32| {
+                    if wide > i32::from(i8::MAX) {
+                        Saturation::Upper
+                    } else if wide < i32::from(i8::MIN) {
+                        Saturation::Lower
+                    } else {
+                        Saturation::None
+                    }
+                };
+                assert_eq!(x.add_reporting(y).1, expected(wa + wb));
+                assert_eq!(x.sub_reporting(y).1, expected(wa - wb));
+                assert_eq!(x.mul_reporting(y).1, expected(wa * wb));
+            }
+        }
+    }
+
+    #[test]
+    fn test_sum() {
+        let values = [1u64, 2, 3, 4];
+        let total: SaturatingU64 = values.iter().copied().map(SaturatingU64::from).sum();
+        assert_eq!(total, SaturatingU64::from(10));
+        let numbers: Vec<SaturatingU64> = vec![u64::MAX.into(), 1.into()];
+        assert_eq!(numbers.iter().sum::<SaturatingU64>(), u64::MAX);
+        assert_eq!(numbers.into_iter().sum::<SaturatingU64>(), u64::MAX);
+        assert_eq!(
+            std::iter::empty::<SaturatingU64>().sum::<SaturatingU64>(),
+            0
+        );
+        // A signed sum is not stuck at the bound it hit.
+        let signed = [i8::MAX, 100, -100].iter().copied().map(SaturatingI8::from);
+        assert_eq!(signed.sum::<SaturatingI8>(), i8::MAX - 100);
+    }
+
+    #[test]
+    fn test_sum_stops_early_at_max() {
+        let mut seen = 0;
+        let total: SaturatingU8 = (0..1000)
+            .map(|_| {
+                seen += 1;
+                SaturatingU8::from(100)
+            })
+            .sum();
+        assert_eq!(total, u8::MAX);
+        assert_eq!(seen, 3);
+    }
+
+    #[test]
+    fn test_product() {
+        let values = [2u32, 3, 4];
+        let total: SaturatingU32 = values.iter().copied().map(SaturatingU32::from).product();
+        assert_eq!(total, 24);
+        let numbers = [SaturatingI32::from(i32::MIN), SaturatingI32::from(-1)];
+        assert_eq!(numbers.iter().product::<SaturatingI32>(), i32::MAX);
+        assert_eq!(
+            std::iter::empty::<SaturatingU32>().product::<SaturatingU32>(),
+            1
+        );
+        let numbers = vec![SaturatingU32::from(u32::MAX), 2.into(), 0.into()];
+        assert_eq!(numbers.into_iter().product::<SaturatingU32>(), 0);
+    }
+
+    #[test]
+    fn test_product_stops_early_at_zero() {
+        let mut seen = 0;
+        let total: SaturatingI64 = (0..1000)
+            .map(|i| {
+                seen += 1;
+                SaturatingI64::from(5 - i)
+            })
+            .product();
+        assert_eq!(total, 0);
+        assert_eq!(seen, 6);
+    }
+
+    #[test]
+    fn test_formatting() {
+        let x = SaturatingU64::from(255);
+        assert_eq!(format!("{}", x), "255");
+        assert_eq!(format!("{:>6}", x), "   255");
+        assert_eq!(format!("{:*<6}", x), "255***");
+        assert_eq!(format!("{:+}", x), "+255");
+        assert_eq!(format!("{:06}", x), "000255");
+        assert_eq!(format!("{:x}", x), "ff");
+        assert_eq!(format!("{:#X}", x), "0xFF");
+        assert_eq!(format!("{:#o}", x), "0o377");
+        assert_eq!(format!("{:#010b}", SaturatingU8::from(5)), "0b00000101");
+        assert_eq!(format!("{:e}", x), "2.55e2");
+        assert_eq!(format!("{:E}", x), "2.55E2");
+        assert_eq!(format!("{:x}", SaturatingI8::from(-1)), "ff");
+    }
+
+    #[test]
+    fn test_alternate_display_marks_saturation() {
+        assert_eq!(
+            format!("{:#}", SaturatingU64::from(u64::MAX)),
+            "18446744073709551615 (saturated)"
+        );
+        assert_eq!(
+            format!("{}", SaturatingU64::from(u64::MAX)),
+            "18446744073709551615"
+        );
+        assert_eq!(format!("{:#}", SaturatingU64::from(0)), "0");
+        assert_eq!(format!("{:#}", SaturatingU64::from(7)), "7");
+        assert_eq!(
+            format!("{:#}", SaturatingI8::from(i8::MIN)),
+            "-128 (saturated)"
+        );
+        assert_eq!(
+            format!("{:>#20}", SaturatingU8::from(u8::MAX)),
+            "     255 (saturated)"
+        );
+        assert_eq!(
+            format!("{:*<#18}", SaturatingI8::from(i8::MAX)),
+            "127 (saturated)***"
+        );
+        assert_eq!(
+            format!("{:-^+#19}", SaturatingI8::from(i8::MAX)),
+            "-+127 (saturated)--"
+        );
+        assert_eq!(
+            format!("{:>#5}", SaturatingU8::from(u8::MAX)),
+            "255 (saturated)"
+        );
+        assert_eq!(format!("{:>#5}", SaturatingU8::from(7)), "    7");
+    }
+
+    #[test]
+    fn test_const_fns() {
+        const MAX_GAS: SaturatingU64 = SaturatingU64::new(u64::MAX - 10);
+        const LIMIT: SaturatingU64 = MAX_GAS.add(SaturatingU64::new(100));
+        static FLOOR: SaturatingI8 = SaturatingI8::MIN.sub(SaturatingI8::ONE);
+        const DOUBLED: SaturatingI8 = SaturatingI8::new(100).mul(SaturatingI8::new(2));
+        const RAW: u64 = LIMIT.get();
+        assert_eq!(LIMIT, SaturatingU64::MAX);
+        assert_eq!(RAW, u64::MAX);
+        assert_eq!(FLOOR, SaturatingI8::from(i8::MIN));
+        assert_eq!(DOUBLED, SaturatingI8::MAX);
+        assert_eq!(
+            SaturatingU8::ZERO.sub(SaturatingU8::ONE),
+            SaturatingU8::ZERO
         );
         assert_eq!(
-            SaturatingU64::from(std::u64::MAX) * SaturatingU64::from(10),
-            SaturatingU64::from(std::u64::MAX)
+            SaturatingU128::new(6).mul(SaturatingU128::new(7)),
+            SaturatingU128::from(42)
         );
+        // Operators still work alongside the inherent methods.
+        assert_eq!(SaturatingU8::new(250) + 10, SaturatingU8::MAX);
     }
 }
@@ -0,0 +1,354 @@
+//! `num-traits` support, enabled by the `num-traits` feature.
+//!
+//! The arithmetic traits are built on the policy traits, so every alias gets
+//! them under every policy. Conversions through `ToPrimitive` and
+//! `FromPrimitive` saturate: they only return `None` for NaN, and for poisoned
+//! values.
+
+use crate::{
+    AbsPolicy, AddPolicy, DivPolicy, HasSaturatingAdd, HasSaturatingDiv, HasSaturatingMul,
+    HasSaturatingProduct, HasSaturatingSub, HasSaturatingSum, MulPolicy, NegPolicy, Number,
+    OverflowPolicy, Saturation, SubPolicy,
+};
+use core::ops::{Add, Mul, Sub};
+use num_traits::{Bounded, FromPrimitive, Num, One, Signed, ToPrimitive, Unsigned, Zero};
+
+impl<T: Add<Output = T> + HasSaturatingSum + PartialEq, P: AddPolicy<T>> Zero for Number<T, P> {
+    fn zero() -> Self {
+        Self::from(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == T::zero()
+    }
+}
+
+impl<T: Mul<Output = T> + HasSaturatingProduct, P: MulPolicy<T>> One for Number<T, P> {
+    fn one() -> Self {
+        Self::from(T::one())
+    }
+}
+
+impl<T: Bounded, P: OverflowPolicy> Bounded for Number<T, P> {
+    fn min_value() -> Self {
+        Self::from(T::min_value())
+    }
+
+    fn max_value() -> Self {
+        Self::from(T::max_value())
+    }
+}
+
+impl<T, P> Num for Number<T, P>
+where
+    T: Num + HasSaturatingSum + HasSaturatingProduct + HasSaturatingSub + HasSaturatingDiv,
+    P: AddPolicy<T> + SubPolicy<T> + MulPolicy<T> + DivPolicy<T>,
+{
+    type FromStrRadixErr = T::FromStrRadixErr;
+
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(src, radix).map(Self::from)
+    }
+}
+
+impl<T, P> Unsigned for Number<T, P>
+where
+    T: Unsigned + HasSaturatingSum + HasSaturatingProduct + HasSaturatingSub + HasSaturatingDiv,
+    P: AddPolicy<T> + SubPolicy<T> + MulPolicy<T> + DivPolicy<T>,
+{
+}
+
+impl<T, P> Signed for Number<T, P>
+where
+    T: Signed
+        + PartialOrd
+        + HasSaturatingSum
+        + HasSaturatingProduct
+        + HasSaturatingSub
+        + HasSaturatingDiv,
+    P: AddPolicy<T> + SubPolicy<T> + MulPolicy<T> + DivPolicy<T> + NegPolicy<T> + AbsPolicy<T>,
+{
+    fn abs(&self) -> Self {
+        let (value, overflow) = P::policy_abs(&self.0);
+        Number(value, self.1.merge(P::default(), overflow, "negate"))
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self.0 <= other.0 {
+            Number(
+                <T as Zero>::zero(),
+                self.1.merge(other.1, Saturation::None, "subtract"),
+            )
+        } else {
+            let (value, overflow) = P::policy_sub(&self.0, &other.0);
+            Number(value, self.1.merge(other.1, overflow, "subtract"))
+        }
+    }
+
+    fn signum(&self) -> Self {
+        Number(self.0.signum(), self.1)
+    }
+
+    fn is_positive(&self) -> bool {
+        self.0.is_positive()
+    }
+
+    fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+}
+
+// The `Saturating*` traits saturate under every policy, without poisoning the
+// result.
+
+impl<T: Add<Output = T> + HasSaturatingAdd, P: AddPolicy<T>> num_traits::SaturatingAdd
+    for Number<T, P>
+{
+    fn saturating_add(&self, v: &Self) -> Self {
+        Number(
+            self.0.do_saturating_add(&v.0),
+            self.1.merge(v.1, Saturation::None, "add"),
+        )
+    }
+}
+
+impl<T: Sub<Output = T> + HasSaturatingSub, P: SubPolicy<T>> num_traits::SaturatingSub
+    for Number<T, P>
+{
+    fn saturating_sub(&self, v: &Self) -> Self {
+        Number(
+            self.0.do_saturating_sub(&v.0),
+            self.1.merge(v.1, Saturation::None, "subtract"),
+        )
+    }
+}
+
+impl<T: Mul<Output = T> + HasSaturatingMul, P: MulPolicy<T>> num_traits::SaturatingMul
+    for Number<T, P>
+{
+    fn saturating_mul(&self, v: &Self) -> Self {
+        Number(
+            self.0.do_saturating_mul(&v.0),
+            self.1.merge(v.1, Saturation::None, "multiply"),
+        )
+    }
+}
+
+// A value that does not fit in the target lies beyond whichever of its bounds
+// is on the same side of zero as the value.
+
+macro_rules! saturating_to {
+    ($($method:ident: $t:ty),*) => {
+        $(
+            fn $method(&self) -> Option<$t> {
+                if self.1.is_poisoned() {
+                    return None;
+                }
+                Some(self.0.$method().unwrap_or_else(|| {
+                    if self.0 < T::zero() {
+                        <$t>::MIN
+                    } else {
+                        <$t>::MAX
+                    }
+                }))
+            }
+        )*
+    };
+}
+
+impl<T: ToPrimitive + Zero + PartialOrd, P: OverflowPolicy> ToPrimitive for Number<T, P> {
+    saturating_to!(
+        to_i8: i8,
+        to_i16: i16,
+        to_i32: i32,
+        to_i64: i64,
+        to_i128: i128,
+        to_isize: isize,
+        to_u8: u8,
+        to_u16: u16,
+        to_u32: u32,
+        to_u64: u64,
+        to_u128: u128,
+        to_usize: usize
+    );
+
+    fn to_f32(&self) -> Option<f32> {
+        if self.1.is_poisoned() {
+            None
+        } else {
+            self.0.to_f32()
+        }
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        if self.1.is_poisoned() {
+            None
+        } else {
+            self.0.to_f64()
+        }
+    }
+}
+
+macro_rules! saturating_from {
+    ($($method:ident: $t:ty),*) => {
+        $(
+            fn $method(n: $t) -> Option<Self> {
+                Some(Self::from(T::$method(n).unwrap_or_else(|| {
+                    if n < (0 as $t) {
+                        T::min_value()
+                    } else {
+                        T::max_value()
+                    }
+                })))
+            }
+        )*
+    };
+}
+
+impl<T: FromPrimitive + Bounded, P: OverflowPolicy> FromPrimitive for Number<T, P> {
+    saturating_from!(
+        from_i8: i8,
+        from_i16: i16,
+        from_i32: i32,
+        from_i64: i64,
+        from_i128: i128,
+        from_isize: isize,
+        from_u8: u8,
+        from_u16: u16,
+        from_u32: u32,
+        from_u64: u64,
+        from_u128: u128,
+        from_usize: usize
+    );
+
+    fn from_f32(n: f32) -> Option<Self> {
+        Self::from_f64(f64::from(n))
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        if n.is_nan() {
+            return None;
+        }
+        Some(Self::from(T::from_f64(n).unwrap_or_else(|| {
+            if n < 0.0 {
+                T::min_value()
+            } else {
+                T::max_value()
+            }
+        })))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{SaturatingI32, SaturatingI8, SaturatingU64, SaturatingU8};
+
+    fn generic_sum<N: Num + Copy>(values: &[N]) -> N {
+        values.iter().fold(N::zero(), |acc, &v| acc + v)
+    }
+
+    #[test]
+    fn test_identities_and_bounds() {
+        assert_eq!(SaturatingU64::zero(), SaturatingU64::from(0));
+        assert!(SaturatingU64::from(0).is_zero());
+        assert!(!SaturatingU64::from(1).is_zero());
+        assert_eq!(SaturatingU64::one(), SaturatingU64::from(1));
+        assert_eq!(SaturatingI8::min_value(), SaturatingI8::from(i8::MIN));
+        assert_eq!(SaturatingI8::max_value(), SaturatingI8::from(i8::MAX));
+    }
+
+    #[test]
+    fn test_generic_code() {
+        let values = [SaturatingU8::from(200), SaturatingU8::from(100)];
+        assert_eq!(generic_sum(&values), SaturatingU8::from(u8::MAX));
+        assert_eq!(
+            SaturatingU64::from_str_radix("ff", 16),
+            Ok(SaturatingU64::from(255))
+        );
+        let a = SaturatingU8::from(250);
+        assert_eq!(
+            num_traits::SaturatingAdd::saturating_add(&a, &a),
+            SaturatingU8::from(u8::MAX)
+        );
+        assert_eq!(
+            num_traits::SaturatingSub::saturating_sub(&SaturatingU8::from(1), &a),
+            SaturatingU8::from(0)
+        );
+        assert_eq!(
+            num_traits::SaturatingMul::saturating_mul(&a, &a),
+            SaturatingU8::from(u8::MAX)
+        );
+    }
+
+    #[test]
+    fn test_other_policies() {
+        type N = Number<u8, crate::Checked>;
+        let values = [N::from(200), N::from(100)];
+        assert!(generic_sum(&values).is_poisoned());
+        assert_eq!(generic_sum(&values).to_u8(), None);
+        assert_eq!(generic_sum(&values[..1]).to_u8(), Some(200));
+        assert_eq!(N::from_u64(500).and_then(|n| n.checked()), Some(u8::MAX));
+        assert!(!N::zero().is_poisoned());
+        assert_eq!(
+            num_traits::SaturatingAdd::saturating_add(&values[0], &values[1]).checked(),
+            Some(u8::MAX)
+        );
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(
+            Signed::abs(&SaturatingI8::from(i8::MIN)),
+            SaturatingI8::from(i8::MAX)
+        );
+        assert_eq!(
+            SaturatingI8::from(i8::MAX).abs_sub(&SaturatingI8::from(i8::MIN)),
+            SaturatingI8::from(i8::MAX)
+        );
+        assert_eq!(
+            SaturatingI8::from(3).abs_sub(&SaturatingI8::from(5)),
+            SaturatingI8::from(0)
+        );
+        assert_eq!(SaturatingI32::from(-7).signum(), SaturatingI32::from(-1));
+        assert!(SaturatingI32::from(-7).is_negative());
+        assert!(SaturatingI32::from(7).is_positive());
+    }
+
+    #[test]
+    fn test_to_primitive_saturates() {
+        let big = SaturatingU64::from(u64::MAX);
+        assert_eq!(big.to_u8(), Some(u8::MAX));
+        assert_eq!(big.to_i64(), Some(i64::MAX));
+        assert_eq!(big.to_u128(), Some(u128::from(u64::MAX)));
+        assert_eq!(big.to_f64(), Some(u64::MAX as f64));
+        let negative = SaturatingI32::from(-300);
+        assert_eq!(negative.to_u32(), Some(0));
+        assert_eq!(negative.to_i8(), Some(i8::MIN));
+        assert_eq!(negative.to_i16(), Some(-300));
+    }
+
+    #[test]
+    fn test_from_primitive_saturates() {
+        assert_eq!(SaturatingU8::from_i64(-5), Some(SaturatingU8::from(0)));
+        assert_eq!(
+            SaturatingU8::from_u64(500),
+            Some(SaturatingU8::from(u8::MAX))
+        );
+        assert_eq!(SaturatingU8::from_u8(7), Some(SaturatingU8::from(7)));
+        assert_eq!(
+            SaturatingI8::from_i128(i128::MIN),
+            Some(SaturatingI8::from(i8::MIN))
+        );
+        assert_eq!(
+            SaturatingI8::from_f64(1e10),
+            Some(SaturatingI8::from(i8::MAX))
+        );
+        assert_eq!(
+            SaturatingI8::from_f32(-1e10),
+            Some(SaturatingI8::from(i8::MIN))
+        );
+        assert_eq!(SaturatingI8::from_f64(-2.5), Some(SaturatingI8::from(-2)));
+        assert_eq!(SaturatingI8::from_f64(f64::NAN), None);
+    }
+}
@@ -0,0 +1,292 @@
+//! Parsing of saturating numbers from strings.
+//!
+//! Both the `FromStr` impl and `Number::parse_strict` accept an optional
+//! `+`/`-` sign, an optional `0x`, `0o` or `0b` radix prefix and `_` digit
+//! separators, e.g. `-0x_7fff_ffff`.
+
+use crate::{Number, OverflowPolicy};
+use core::{fmt, str::FromStr};
+
+/// The reason a string could not be parsed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The string was empty.
+    Empty,
+    /// The string contained a character that is not a digit in its radix, or
+    /// had no digits at all.
+    InvalidDigit,
+    /// The value is larger than the type's maximum. Not returned by the
+    /// `FromStr` impl of `SaturatingNumber`.
+    PosOverflow,
+    /// The value is smaller than the type's minimum. Not returned by the
+    /// `FromStr` impl of `SaturatingNumber`.
+    NegOverflow,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseSaturatingError {
+    kind: ParseErrorKind,
+}
+
+impl ParseSaturatingError {
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+}
+
+impl From<ParseErrorKind> for ParseSaturatingError {
+    fn from(kind: ParseErrorKind) -> Self {
+        Self { kind }
+    }
+}
+
+impl fmt::Display for ParseSaturatingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.kind {
+            ParseErrorKind::Empty => "cannot parse number from empty string",
+            ParseErrorKind::InvalidDigit => "invalid digit found in string",
+            ParseErrorKind::PosOverflow => "number too large to fit in target type",
+            ParseErrorKind::NegOverflow => "number too small to fit in target type",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseSaturatingError {}
+
+pub trait HasSaturatingParse: Sized {
+    /// Parses `src`, clamping out of range values to the nearest bound if
+    /// `saturate` is set and reporting an overflow error otherwise.
+    fn do_parse(src: &str, saturate: bool) -> Result<Self, ParseSaturatingError>;
+}
+
+/// Splits `src` into its sign, radix and digit part.
+pub(crate) fn split(src: &str) -> Result<(bool, u32, &str), ParseSaturatingError> {
+    if src.is_empty() {
+        return Err(ParseErrorKind::Empty.into());
+    }
+    let (negative, rest) = match src.as_bytes()[0] {
+        b'-' => (true, &src[1..]),
+        b'+' => (false, &src[1..]),
+        _ => (false, src),
+    };
+    let (radix, digits) = match rest.get(..2) {
+        Some("0x") | Some("0X") => (16, &rest[2..]),
+        Some("0o") | Some("0O") => (8, &rest[2..]),
+        Some("0b") | Some("0B") => (2, &rest[2..]),
+        _ => (10, rest),
+    };
+    if !digits.bytes().any(|b| b != b'_') {
+        return Err(ParseErrorKind::InvalidDigit.into());
+    }
+    Ok((negative, radix, digits))
+}
+
+/// Yields the value of each digit in `digits`, skipping `_` separators.
+pub(crate) fn digits(
+    digits: &str,
+    radix: u32,
+) -> impl Iterator<Item = Result<u32, ParseSaturatingError>> + '_ {
+    digits.chars().filter(|&c| c != '_').map(move |c| {
+        c.to_digit(radix)
+            .ok_or_else(|| ParseErrorKind::InvalidDigit.into())
+    })
+}
+
+macro_rules! impl_has_saturating_parse {
+    ($($t:ty),*) => {
+        $(
+            impl HasSaturatingParse for $t {
+                fn do_parse(src: &str, saturate: bool) -> Result<Self, ParseSaturatingError> {
+                    let (negative, radix, rest) = split(src)?;
+                    let mut value: $t = 0;
+                    let mut overflowed = false;
+                    // Keep scanning after an overflow so that a malformed
+                    // string is reported as such rather than saturated.
+                    for digit in digits(rest, radix) {
+                        let digit = digit? as $t;
+                        if overflowed {
+                            continue;
+                        }
+                        let next = value.checked_mul(radix as $t).and_then(|v| {
+                            if negative {
+                                v.checked_sub(digit)
+                            } else {
+                                v.checked_add(digit)
+                            }
+                        });
+                        match next {
+                            Some(next) => value = next,
+                            None => overflowed = true,
+                        }
+                    }
+                    if !overflowed {
+                        Ok(value)
+                    } else if saturate {
+                        Ok(if negative { <$t>::MIN } else { <$t>::MAX })
+                    } else if negative {
+                        Err(ParseErrorKind::NegOverflow.into())
+                    } else {
+                        Err(ParseErrorKind::PosOverflow.into())
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_has_saturating_parse!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Under `Saturate`, parses with saturating semantics: values outside the
+/// range of `T` are clamped to its nearest bound rather than rejected. The
+/// other policies reject them like `parse_strict`.
+impl<T: HasSaturatingParse, P: OverflowPolicy> FromStr for Number<T, P> {
+    type Err = ParseSaturatingError;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        T::do_parse(src, P::SATURATES).map(Self::from)
+    }
+}
+
+impl<T: HasSaturatingParse, P: OverflowPolicy> Number<T, P> {
+    /// Parses like `FromStr`, but returns an overflow error for values outside
+    /// the range of `T` instead of clamping them.
+    pub fn parse_strict(src: &str) -> Result<Self, ParseSaturatingError> {
+        T::do_parse(src, false).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{SaturatingI64, SaturatingI8, SaturatingU64, SaturatingU8};
+
+    fn kind<T>(result: Result<T, ParseSaturatingError>) -> ParseErrorKind {
+        match result {
+            Ok(_) => panic!("expected an error"),
+            Err(e) => e.kind(),
+        }
+    }
+
+    #[test]
+    fn test_saturating_parse() {
+        assert_eq!("42".parse::<SaturatingU64>(), Ok(SaturatingU64::from(42)));
+        assert_eq!(
+            "99999999999999999999".parse::<SaturatingU64>(),
+            Ok(SaturatingU64::from(u64::MAX))
+        );
+        assert_eq!("-5".parse::<SaturatingU64>(), Ok(SaturatingU64::from(0)));
+        assert_eq!("-0".parse::<SaturatingU64>(), Ok(SaturatingU64::from(0)));
+        assert_eq!("+7".parse::<SaturatingU8>(), Ok(SaturatingU8::from(7)));
+        assert_eq!(
+            "-129".parse::<SaturatingI8>(),
+            Ok(SaturatingI8::from(i8::MIN))
+        );
+        assert_eq!(
+            "-128".parse::<SaturatingI8>(),
+            Ok(SaturatingI8::from(i8::MIN))
+        );
+        assert_eq!(
+            "128".parse::<SaturatingI8>(),
+            Ok(SaturatingI8::from(i8::MAX))
+        );
+    }
+
+    #[test]
+    fn test_strict_parse() {
+        assert_eq!(
+            SaturatingU64::parse_strict("42"),
+            Ok(SaturatingU64::from(42))
+        );
+        assert_eq!(
+            kind(SaturatingU64::parse_strict("99999999999999999999")),
+            ParseErrorKind::PosOverflow
+        );
+        assert_eq!(
+            kind(SaturatingU64::parse_strict("-5")),
+            ParseErrorKind::NegOverflow
+        );
+        assert_eq!(
+            kind(SaturatingI8::parse_strict("-129")),
+            ParseErrorKind::NegOverflow
+        );
+        assert_eq!(
+            SaturatingI8::parse_strict("-128"),
+            Ok(SaturatingI8::from(i8::MIN))
+        );
+    }
+
+    #[test]
+    fn test_prefixes_and_separators() {
+        assert_eq!("0xff".parse::<SaturatingU8>(), Ok(SaturatingU8::from(255)));
+        assert_eq!("0x100".parse::<SaturatingU8>(), Ok(SaturatingU8::from(255)));
+        assert_eq!("0o17".parse::<SaturatingU8>(), Ok(SaturatingU8::from(15)));
+        assert_eq!("0b1010".parse::<SaturatingU8>(), Ok(SaturatingU8::from(10)));
+        assert_eq!(
+            "1_000_000".parse::<SaturatingU64>(),
+            Ok(SaturatingU64::from(1_000_000))
+        );
+        assert_eq!(
+            SaturatingI64::parse_strict("-0x_7fff_ffff"),
+            Ok(SaturatingI64::from(-0x7fff_ffff))
+        );
+        assert_eq!(
+            SaturatingU64::parse_strict("0XFFFF_FFFF_FFFF_FFFF"),
+            Ok(SaturatingU64::from(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(kind("".parse::<SaturatingU64>()), ParseErrorKind::Empty);
+        assert_eq!(
+            kind("-".parse::<SaturatingU64>()),
+            ParseErrorKind::InvalidDigit
+        );
+        assert_eq!(
+            kind("0x".parse::<SaturatingU64>()),
+            ParseErrorKind::InvalidDigit
+        );
+        assert_eq!(
+            kind("_".parse::<SaturatingU64>()),
+            ParseErrorKind::InvalidDigit
+        );
+        assert_eq!(
+            kind("12a".parse::<SaturatingU64>()),
+            ParseErrorKind::InvalidDigit
+        );
+        assert_eq!(
+            kind("0b102".parse::<SaturatingU64>()),
+            ParseErrorKind::InvalidDigit
+        );
+        assert_eq!(
+            kind(" 1".parse::<SaturatingU64>()),
+            ParseErrorKind::InvalidDigit
+        );
+        // Garbage after an overflow is still an error, not a saturated value.
+        assert_eq!(
+            kind("99999999999999999999x".parse::<SaturatingU64>()),
+            ParseErrorKind::InvalidDigit
+        );
+        assert_eq!(
+            "1x".parse::<SaturatingU64>().unwrap_err().to_string(),
+            "invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_i8_against_std() {
+        for value in -1000..=1000i32 {
+            let text = value.to_string();
+            let expected = value.clamp(i32::from(i8::MIN), i32::from(i8::MAX)) as i8;
+            assert_eq!(
+                text.parse::<SaturatingI8>(),
+                Ok(SaturatingI8::from(expected))
+            );
+            assert_eq!(
+                SaturatingI8::parse_strict(&text).ok(),
+                text.parse::<i8>().ok().map(SaturatingI8::from)
+            );
+        }
+    }
+}
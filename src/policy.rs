@@ -0,0 +1,618 @@
+//! Overflow policies for `Number<T, P>`. Switching a module between them only
+//! takes changing the policy parameter of its type aliases:
+//!
+//! ```
+//! use saturating_numbers::{Checked, Number};
+//!
+//! type Gas = Number<u64, Checked>;
+//!
+//! let total = Gas::from(u64::MAX) + Gas::from(1) - Gas::from(5);
+//! assert!(total.is_poisoned());
+//! assert_eq!(total.checked(), None);
+//! assert_eq!((Gas::from(2) * Gas::from(3)).checked(), Some(6));
+//!
+//! let limits: Vec<Gas> = vec!["10".parse().unwrap(), Gas::from(30) / Gas::from(3)];
+//! assert_eq!(limits.into_iter().sum::<Gas>().checked(), Some(20));
+//! assert!("1e100".parse::<Gas>().is_err());
+//! ```
+//!
+//! Every operator follows the policy. Conversions and the explicitly
+//! saturating methods such as `add_reporting` are specific to `Saturate`.
+
+use crate::{
+    fmt_marked, DivByZero, HasSaturatingAbs, HasSaturatingAdd, HasSaturatingDiv, HasSaturatingMul,
+    HasSaturatingNeg, HasSaturatingPow, HasSaturatingShl, HasSaturatingSub, HasWrappingAbs,
+    HasWrappingAdd, HasWrappingDiv, HasWrappingMul, HasWrappingNeg, HasWrappingPow, HasWrappingShl,
+    HasWrappingSub, Number, Saturation,
+};
+use core::fmt;
+
+/// How the operators of `Number` handle results that do not fit.
+///
+/// A value of the policy type is stored alongside every number, which lets
+/// stateful policies like `Checked` carry information from one operation to
+/// the next. Stateless policies are zero-sized.
+pub trait OverflowPolicy: Copy + Default {
+    /// Returns the state of a result computed from operands in the states
+    /// `self` and `rhs`, given which bound, if any, the exact result lies
+    /// beyond. `op` names the operation, e.g. `"add"`.
+    fn merge(self, rhs: Self, overflow: Saturation, op: &'static str) -> Self;
+
+    /// Returns true if an earlier operation on the value overflowed and the
+    /// value can no longer be trusted.
+    fn is_poisoned(self) -> bool {
+        false
+    }
+
+    /// Whether overflowing results are clamped with no other effect, as under
+    /// `Saturate`. Parsing then clamps out of range values too instead of
+    /// rejecting them, and sums and products stop early once no further
+    /// operand can change them.
+    const SATURATES: bool = false;
+}
+
+/// Clamps results to the bounds of the inner type. The default policy.
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Hash, Copy, Clone, Default)]
+pub struct Saturate;
+
+/// Wraps results around the bounds of the inner type.
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Hash, Copy, Clone, Default)]
+pub struct Wrap;
+
+/// Panics on overflow, in release builds too.
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Hash, Copy, Clone, Default)]
+pub struct Panic;
+
+/// Poisons the result of an overflowing operation, and every result computed
+/// from a poisoned value. The value of a poisoned number is the saturated
+/// result, but `Number::checked` returns `None` for it.
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Hash, Copy, Clone, Default)]
+pub struct Checked {
+    poisoned: bool,
+}
+
+impl OverflowPolicy for Saturate {
+    fn merge(self, _: Self, _: Saturation, _: &'static str) -> Self {
+        self
+    }
+
+    const SATURATES: bool = true;
+}
+
+impl OverflowPolicy for Wrap {
+    fn merge(self, _: Self, _: Saturation, _: &'static str) -> Self {
+        self
+    }
+}
+
+impl OverflowPolicy for Panic {
+    fn merge(self, _: Self, overflow: Saturation, op: &'static str) -> Self {
+        if overflow != Saturation::None {
+            panic!("attempt to {} with overflow", op);
+        }
+        self
+    }
+}
+
+impl OverflowPolicy for Checked {
+    fn merge(self, rhs: Self, overflow: Saturation, _: &'static str) -> Self {
+        Self {
+            poisoned: self.poisoned || rhs.poisoned || overflow != Saturation::None,
+        }
+    }
+
+    fn is_poisoned(self) -> bool {
+        self.poisoned
+    }
+}
+
+impl<T> Number<T, Checked> {
+    pub fn is_poisoned(&self) -> bool {
+        self.1.poisoned
+    }
+
+    /// Returns the value, or `None` if any operation that produced it
+    /// overflowed.
+    pub fn checked(self) -> Option<T> {
+        if self.1.poisoned {
+            None
+        } else {
+            Some(self.0)
+        }
+    }
+}
+
+/// With the `strict-debug` feature, debug builds panic whenever an operator
+/// on a saturating number clamps, since that usually means a bug in tests.
+/// Every `Saturate` impl of a policy trait goes through this check.
+/// This crate's own unit tests saturate on purpose and are exempt.
+pub(crate) const STRICT_DEBUG: bool =
+    cfg!(all(feature = "strict-debug", debug_assertions, not(test)));
+
+/// Panics if `saturation` reports that the operator `op` clamped, showing
+/// its operands.
+fn strict_debug_check(op: &str, operands: &[&dyn fmt::Display], saturation: Saturation) {
+    let bound = match saturation {
+        Saturation::None => return,
+        Saturation::Upper => "upper",
+        Saturation::Lower => "lower",
+    };
+    panic!(
+        "`{}` of {} saturated at the {} bound (strict-debug)",
+        op,
+        Operands(operands),
+        bound
+    );
+}
+
+/// Shows an operand of type `T` through the `fmt_operand` hook of whichever
+/// `HasSaturating*` trait the operation needs.
+struct Operand<'a, T>(&'a T, fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result);
+
+impl<T> fmt::Display for Operand<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.1)(self.0, f)
+    }
+}
+
+struct Operands<'a>(&'a [&'a dyn fmt::Display]);
+
+impl fmt::Display for Operands<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, operand) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" and ")?;
+            }
+            operand.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+// Each policy implements an operation for the inner types that support what it
+// needs, so that e.g. saturating numbers do not require wrapping arithmetic.
+
+macro_rules! policy_op {
+    (@checked $saturation:expr) => {
+        $saturation
+    };
+    (@checked $saturation:expr, |$checked:ident| $check:expr) => {{
+        let $checked = $saturation;
+        $check
+    }};
+    (
+        $(#[$attr:meta])*
+        $Policy:ident($symbol:literal): $apply:ident, $apply_assign:ident,
+        $Has:ident: $do_op:ident, $do_op_assign:ident, $do_op_reporting:ident,
+        $HasWrapping:ident: $do_wrapping:ident
+    ) => {
+        $(#[$attr])*
+        pub trait $Policy<T>: OverflowPolicy {
+            /// Returns the result and which bound, if any, the exact result
+            /// lies beyond. Policies that do not care may report
+            /// `Saturation::None`.
+            fn $apply(lhs: &T, rhs: &T) -> (T, Saturation);
+
+            /// Like the above, but updates `lhs` in place.
+            fn $apply_assign(lhs: &mut T, rhs: &T) -> Saturation;
+        }
+
+        impl<T: $Has> $Policy<T> for Saturate {
+            fn $apply(lhs: &T, rhs: &T) -> (T, Saturation) {
+                if STRICT_DEBUG {
+                    let (result, saturation) = lhs.$do_op_reporting(rhs);
+                    strict_debug_check(
+                        $symbol,
+                        &[&Operand(lhs, T::fmt_operand), &Operand(rhs, T::fmt_operand)],
+                        saturation,
+                    );
+                    (result, saturation)
+                } else {
+                    (lhs.$do_op(rhs), Saturation::None)
+                }
+            }
+
+            fn $apply_assign(lhs: &mut T, rhs: &T) -> Saturation {
+                if STRICT_DEBUG {
+                    let (result, saturation) = lhs.$do_op_reporting(rhs);
+                    strict_debug_check(
+                        $symbol,
+                        &[&Operand(lhs, T::fmt_operand), &Operand(rhs, T::fmt_operand)],
+                        saturation,
+                    );
+                    *lhs = result;
+                    saturation
+                } else {
+                    lhs.$do_op_assign(rhs);
+                    Saturation::None
+                }
+            }
+        }
+
+        impl<T: $HasWrapping> $Policy<T> for Wrap {
+            fn $apply(lhs: &T, rhs: &T) -> (T, Saturation) {
+                (lhs.$do_wrapping(rhs), Saturation::None)
+            }
+
+            fn $apply_assign(lhs: &mut T, rhs: &T) -> Saturation {
+                *lhs = lhs.$do_wrapping(rhs);
+                Saturation::None
+            }
+        }
+
+        impl<T: $Has> $Policy<T> for Panic {
+            fn $apply(lhs: &T, rhs: &T) -> (T, Saturation) {
+                lhs.$do_op_reporting(rhs)
+            }
+
+            fn $apply_assign(lhs: &mut T, rhs: &T) -> Saturation {
+                let (result, saturation) = lhs.$do_op_reporting(rhs);
+                *lhs = result;
+                saturation
+            }
+        }
+
+        impl<T: $Has> $Policy<T> for Checked {
+            fn $apply(lhs: &T, rhs: &T) -> (T, Saturation) {
+                lhs.$do_op_reporting(rhs)
+            }
+
+            fn $apply_assign(lhs: &mut T, rhs: &T) -> Saturation {
+                let (result, saturation) = lhs.$do_op_reporting(rhs);
+                *lhs = result;
+                saturation
+            }
+        }
+    };
+    // Operations without an in-place form, taking any further arguments
+    // after the value. `showing` lists the operands for strict-debug messages,
+    // and `checking` can narrow down which saturation strict-debug rejects.
+    (
+        $(#[$attr:meta])*
+        $Policy:ident($symbol:literal): $apply:ident($value:ident $(, $arg:ident: $Arg:ty)*),
+        showing [$($shown:expr),*],
+        $Has:ident: $do_op:ident, $do_op_reporting:ident,
+        $HasWrapping:ident: $do_wrapping:ident
+        $(, checking |$checked:ident| $check:expr)?
+    ) => {
+        $(#[$attr])*
+        pub trait $Policy<T>: OverflowPolicy {
+            /// Returns the result and which bound, if any, the exact result
+            /// lies beyond. Policies that do not care may report
+            /// `Saturation::None`.
+            fn $apply($value: &T $(, $arg: $Arg)*) -> (T, Saturation);
+        }
+
+        impl<T: $Has> $Policy<T> for Saturate {
+            fn $apply($value: &T $(, $arg: $Arg)*) -> (T, Saturation) {
+                if STRICT_DEBUG {
+                    let (result, saturation) = $value.$do_op_reporting($($arg),*);
+                    let checked = policy_op!(@checked saturation $(, |$checked| $check)?);
+                    strict_debug_check($symbol, &[$($shown),*], checked);
+                    (result, saturation)
+                } else {
+                    ($value.$do_op($($arg),*), Saturation::None)
+                }
+            }
+        }
+
+        impl<T: $HasWrapping> $Policy<T> for Wrap {
+            fn $apply($value: &T $(, $arg: $Arg)*) -> (T, Saturation) {
+                ($value.$do_wrapping($($arg),*), Saturation::None)
+            }
+        }
+
+        impl<T: $Has> $Policy<T> for Panic {
+            fn $apply($value: &T $(, $arg: $Arg)*) -> (T, Saturation) {
+                $value.$do_op_reporting($($arg),*)
+            }
+        }
+
+        impl<T: $Has> $Policy<T> for Checked {
+            fn $apply($value: &T $(, $arg: $Arg)*) -> (T, Saturation) {
+                $value.$do_op_reporting($($arg),*)
+            }
+        }
+    };
+}
+
+policy_op! {
+    /// Addition under a policy.
+    AddPolicy("+"): policy_add, policy_add_assign,
+    HasSaturatingAdd: do_saturating_add, do_saturating_add_assign, do_saturating_add_reporting,
+    HasWrappingAdd: do_wrapping_add
+}
+
+policy_op! {
+    /// Subtraction under a policy.
+    SubPolicy("-"): policy_sub, policy_sub_assign,
+    HasSaturatingSub: do_saturating_sub, do_saturating_sub_assign, do_saturating_sub_reporting,
+    HasWrappingSub: do_wrapping_sub
+}
+
+policy_op! {
+    /// Multiplication under a policy.
+    MulPolicy("*"): policy_mul, policy_mul_assign,
+    HasSaturatingMul: do_saturating_mul, do_saturating_mul_assign, do_saturating_mul_reporting,
+    HasWrappingMul: do_wrapping_mul
+}
+
+policy_op! {
+    /// Division under a policy, with a zero divisor handled according to
+    /// `on_zero`.
+    DivPolicy("/"): policy_div(lhs, rhs: &T, on_zero: DivByZero),
+    showing [&Operand(lhs, T::fmt_operand), &Operand(rhs, T::fmt_operand)],
+    HasSaturatingDiv: do_saturating_div, do_saturating_div_reporting,
+    HasWrappingDiv: do_wrapping_div,
+    // Saturating at a zero divisor is what an explicit `DivByZero::Saturate`
+    // asks for, so only the division itself is checked.
+    checking |saturation| if on_zero == DivByZero::Saturate {
+        lhs.do_saturating_div_reporting(rhs, DivByZero::Zero).1
+    } else {
+        saturation
+    }
+}
+
+policy_op! {
+    /// Left shifts under a policy. Shifting out set bits overflows.
+    ShlPolicy("<<"): policy_shl(lhs, rhs: u32),
+    showing [&Operand(lhs, T::fmt_operand), &rhs],
+    HasSaturatingShl: do_saturating_shl, do_saturating_shl_reporting,
+    HasWrappingShl: do_wrapping_shl
+}
+
+policy_op! {
+    /// Exponentiation under a policy.
+    PowPolicy("pow"): policy_pow(base, exp: u32),
+    showing [&Operand(base, T::fmt_operand), &exp],
+    HasSaturatingPow: do_saturating_pow, do_saturating_pow_reporting,
+    HasWrappingPow: do_wrapping_pow
+}
+
+policy_op! {
+    /// Negation under a policy.
+    NegPolicy("-"): policy_neg(value),
+    showing [&Operand(value, T::fmt_operand)],
+    HasSaturatingNeg: do_saturating_neg, do_saturating_neg_reporting,
+    HasWrappingNeg: do_wrapping_neg
+}
+
+policy_op! {
+    /// Absolute values under a policy.
+    AbsPolicy("abs"): policy_abs(value),
+    showing [&Operand(value, T::fmt_operand)],
+    HasSaturatingAbs: do_saturating_abs, do_saturating_abs_reporting,
+    HasWrappingAbs: do_wrapping_abs
+}
+
+// The alternate form of `Display` marks the saturated values of the saturating
+// policy; for the others it marks poisoned values, if any.
+
+macro_rules! impl_policy_display {
+    ($($P:ty),*) => {
+        $(
+            impl<T: fmt::Display> fmt::Display for Number<T, $P> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    if f.alternate() && self.1.is_poisoned() {
+                        fmt_marked(&self.0, " (poisoned)", f)
+                    } else {
+                        fmt::Display::fmt(&self.0, f)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_policy_display!(Wrap, Panic, Checked);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ParseErrorKind, SaturatingU8, Uint, U256};
+    use core::cmp::Ordering;
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_policies_on_overflow() {
+        let max = u8::MAX;
+        assert_eq!(SaturatingU8::from(max) + 1, SaturatingU8::from(max));
+        assert_eq!(Number::<u8, Wrap>::from(max) + 1, 0);
+        assert_eq!(Number::<u8, Wrap>::from(0) - 1, max);
+        assert_eq!(Number::<i8, Wrap>::from(64) * Number::from(2), i8::MIN);
+        assert_eq!(Number::<u8, Panic>::from(200) + 55, max);
+        assert_eq!(Number::<u8, Checked>::from(200).checked(), Some(200));
+        assert_eq!((Number::<u8, Checked>::from(200) + 56).checked(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn test_panic_policy() {
+        let _ = Number::<u64, Panic>::from(1) - Number::from(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn test_panic_policy_assign() {
+        let mut x = Number::<i32, Panic>::from(i32::MIN);
+        x *= &Number::from(-1);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_poison_propagates() {
+        type N = Number<u16, Checked>;
+        let poisoned = N::from(u16::MAX) * N::from(2);
+        assert!(poisoned.is_poisoned());
+        // Operations that would be exact on their own stay poisoned.
+        let later = N::from(1) + &(poisoned - N::from(u16::MAX));
+        assert!(later.is_poisoned());
+        assert_eq!(later.checked(), None);
+        let mut total = N::from(10);
+        total -= 20;
+        total += 30;
+        assert!(total.is_poisoned());
+        assert_ne!(N::from(0), total);
+        assert_eq!(format!("{:?}", total), "30 (poisoned)");
+        assert_eq!(format!("{:#}", total), "30 (poisoned)");
+        assert_eq!(format!("{}", total), "30");
+        assert_eq!(format!("{:?}", N::from(3)), "3");
+    }
+
+    #[test]
+    fn test_poisoned_never_compares() {
+        type N = Number<u8, Checked>;
+        let poisoned = N::from(250) + N::from(10);
+        assert!(poisoned != u8::MAX);
+        assert!(u8::MAX != poisoned);
+        assert!(poisoned != N::from(u8::MAX));
+        assert!(N::from(u8::MAX) != poisoned);
+        assert!(poisoned != poisoned);
+        assert_eq!(poisoned.partial_cmp(&0), None);
+        assert_eq!(0.partial_cmp(&poisoned), None);
+        assert_eq!(poisoned.partial_cmp(&N::from(u8::MAX)), None);
+        assert_eq!(N::from(0).partial_cmp(&poisoned), None);
+        assert_eq!(poisoned.partial_cmp(&poisoned), None);
+        let exact = N::from(250) + N::from(5);
+        assert_eq!(exact, u8::MAX);
+        assert_eq!(u8::MAX, exact);
+        assert_eq!(exact, N::from(u8::MAX));
+        assert_eq!(254.partial_cmp(&exact), Some(Ordering::Less));
+        assert_eq!(N::from(254).partial_cmp(&exact), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn test_policies_on_other_inner_types() {
+        assert_eq!(
+            Number::<U256, Wrap>::from(U256::MAX) + U256::from(2u8),
+            U256::from(1u8)
+        );
+        assert!((Number::<U256, Checked>::from(U256::MAX) * U256::MAX).is_poisoned());
+        assert_eq!(
+            Number::<Uint<12>, Wrap>::from(Uint::MAX) + Uint::clamped(1),
+            Uint::MIN
+        );
+        assert_eq!(
+            (Number::<Uint<12>, Checked>::from(Uint::MAX) - Uint::MIN).checked(),
+            Some(Uint::MAX)
+        );
+    }
+
+    #[test]
+    fn test_i8_operators_against_reference() {
+        type C = Number<i8, Checked>;
+        type W = Number<i8, Wrap>;
+        let checked = |n: C, expected: Option<i8>| assert_eq!(n.checked(), expected);
+        for a in i8::MIN..=i8::MAX {
+            checked(-C::from(a), a.checked_neg());
+            checked(C::from(a).abs(), a.checked_abs());
+            assert_eq!(-W::from(a), a.wrapping_neg());
+            assert_eq!(W::from(a).abs(), a.wrapping_abs());
+            for n in 0..10 {
+                let wide = i32::from(a) << n;
+                let shifted = i8::try_from(wide).ok();
+                checked(C::from(a) << n, shifted);
+                checked(C::from(a).pow(n), a.checked_pow(n));
+                assert_eq!(W::from(a) << n, a.checked_shl(n).unwrap_or(0));
+                assert_eq!(W::from(a).pow(n), a.wrapping_pow(n));
+                checked(C::from(a) >> n, Some(a >> n.min(7)));
+            }
+            for b in i8::MIN..=i8::MAX {
+                if b == 0 {
+                    continue;
+                }
+                checked(C::from(a) / C::from(b), a.checked_div(b));
+                checked(C::from(a) % C::from(b), Some(a.wrapping_rem(b)));
+                assert_eq!(W::from(a) / W::from(b), a.wrapping_div(b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_other_operators_propagate_poison() {
+        type N = Number<i32, Checked>;
+        let poisoned = N::from(i32::MAX) + N::from(1);
+        assert!((poisoned >> 1).is_poisoned());
+        assert!((poisoned % N::from(7)).is_poisoned());
+        assert!((N::from(7) / poisoned).is_poisoned());
+        assert!((-poisoned).is_poisoned());
+        assert!(poisoned.pow(0).is_poisoned());
+        let mut x = N::from(1);
+        x <<= 31;
+        assert!(x.is_poisoned());
+        let mut y = N::from(i32::MIN);
+        y /= N::from(-1);
+        assert!(y.is_poisoned());
+        assert_eq!(
+            N::from(5).div_with(N::from(0), DivByZero::Zero).checked(),
+            Some(0)
+        );
+        assert!(N::from(5)
+            .div_with(N::from(0), DivByZero::Saturate)
+            .is_poisoned());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to negate with overflow")]
+    fn test_panic_policy_negation() {
+        let _ = -Number::<i64, Panic>::from(i64::MIN);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to shift left with overflow")]
+    fn test_panic_policy_shift() {
+        let _ = Number::<u16, Panic>::from(3) << 15;
+    }
+
+    #[test]
+    fn test_folds_under_every_policy() {
+        type C = Number<u8, Checked>;
+        let values = [C::from(u8::MAX), C::from(0), C::from(1)];
+        // Reaching `MAX` exactly must not end the sum early.
+        assert_eq!(values[..2].iter().sum::<C>().checked(), Some(u8::MAX));
+        assert_eq!(values.iter().sum::<C>().checked(), None);
+        assert_eq!(values.iter().product::<C>().checked(), Some(0));
+        let wrapped: Number<u8, Wrap> = [200, 100].iter().map(|&v| Number::from(v)).sum();
+        assert_eq!(wrapped, 44);
+        let product: Number<u8, Wrap> = vec![Number::from(16), Number::from(17)]
+            .into_iter()
+            .product();
+        assert_eq!(product, 16);
+    }
+
+    #[test]
+    fn test_parse_under_every_policy() {
+        assert_eq!("300".parse::<SaturatingU8>(), Ok(SaturatingU8::from(255)));
+        assert_eq!(
+            "300".parse::<Number<u8, Checked>>().unwrap_err().kind(),
+            ParseErrorKind::PosOverflow
+        );
+        assert_eq!("-1".parse::<Number<i8, Wrap>>(), Ok(Number::from(-1)));
+        assert_eq!(
+            "-129".parse::<Number<i8, Panic>>().unwrap_err().kind(),
+            ParseErrorKind::NegOverflow
+        );
+        assert_eq!(
+            Number::<u8, Checked>::parse_strict("7"),
+            Ok(Number::from(7))
+        );
+    }
+
+    #[test]
+    fn test_same_code_under_every_policy() {
+        fn total<P: AddPolicy<u32> + MulPolicy<u32>>(prices: &[u32]) -> Number<u32, P> {
+            prices
+                .iter()
+                .fold(Number::from(0), |acc, &price| acc + Number::from(price) * 2)
+        }
+        let prices = [1, 2, 3];
+        assert_eq!(total::<Saturate>(&prices), 12);
+        assert_eq!(total::<Wrap>(&prices), 12);
+        assert_eq!(total::<Panic>(&prices), 12);
+        assert_eq!(total::<Checked>(&prices).checked(), Some(12));
+        let big = [u32::MAX / 2, 1];
+        assert_eq!(total::<Saturate>(&big), u32::MAX);
+        assert_eq!(total::<Wrap>(&big), 0);
+        assert_eq!(total::<Checked>(&big).checked(), None);
+    }
+}
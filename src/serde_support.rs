@@ -0,0 +1,387 @@
+//! `serde` support, enabled by the `serde` feature.
+//!
+//! `Number<T, P>` serializes and deserializes exactly like `T`, under every
+//! policy. The submodules provide opt-in alternatives for use with
+//! `#[serde(with = ...)]`:
+//!
+//! * [`clamped`] accepts any integer, clamping out of range and negative
+//!   values instead of rejecting the document.
+//! * [`string`] encodes the value as a decimal string, for consumers such as
+//!   JavaScript that cannot represent 128-bit integers.
+//!
+//! For `u128` and `i128`, [`clamped`] asks the format for a 128-bit integer,
+//! so that every value in range is read exactly. serde_json then rejects
+//! floats and integers outside the range of the 128-bit type instead of
+//! clamping them. [`string`] has to accept strings, so plain integers wider
+//! than 64 bits only succeed when the format reads them exactly.
+
+use crate::{HasSaturatingParse, Number, OverflowPolicy, SaturatingFrom};
+use core::{convert::TryFrom, fmt, marker::PhantomData};
+use serde::{
+    de::{self, Visitor},
+    ser, Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// A poisoned `Number<T, Checked>` fails to serialize rather than passing off
+/// its value as exact.
+impl<T: Serialize, P: OverflowPolicy> Serialize for Number<T, P> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.1.is_poisoned() {
+            return Err(ser::Error::custom("cannot serialize a poisoned number"));
+        }
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, P: OverflowPolicy> Deserialize<'de> for Number<T, P> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// The conversions a lenient visitor needs to clamp any integer into `T`, and
+/// the comparison it needs to tell whether clamping an imprecise float is
+/// exact.
+pub trait ClampFromAny:
+    SaturatingFrom<u64> + SaturatingFrom<i64> + SaturatingFrom<u128> + SaturatingFrom<i128> + PartialEq
+{
+    /// Asks `deserializer` for an integer to clamp into `Self`. Formats such
+    /// as serde_json read integers wider than 64 bits as imprecise floats
+    /// unless asked for a 128-bit integer, so the 128-bit types do that.
+    fn deserialize_clamped<'de, D: Deserializer<'de>, V: Visitor<'de>>(
+        deserializer: D,
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        deserializer.deserialize_any(visitor)
+    }
+}
+
+macro_rules! impl_clamp_from_any {
+    ($($t:ty),*) => {
+        $(
+            impl ClampFromAny for $t {}
+        )*
+    };
+}
+
+impl_clamp_from_any!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl ClampFromAny for u128 {
+    fn deserialize_clamped<'de, D: Deserializer<'de>, V: Visitor<'de>>(
+        deserializer: D,
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        deserializer.deserialize_u128(visitor)
+    }
+}
+
+impl ClampFromAny for i128 {
+    fn deserialize_clamped<'de, D: Deserializer<'de>, V: Visitor<'de>>(
+        deserializer: D,
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        deserializer.deserialize_i128(visitor)
+    }
+}
+
+/// Returns the smallest and largest integers that round to the non-negative
+/// float `v`. Float to integer `as` casts truncate and saturate, which only
+/// widens the interval.
+fn rounding_interval(v: f64) -> (u128, u128) {
+    let below = f64::from_bits(v.to_bits().saturating_sub(1)) as u128;
+    let above = f64::from_bits(v.to_bits() + 1) as u128;
+    let v = v as u128;
+    (v - (v - below) / 2, v + (above - v) / 2)
+}
+
+/// Clamps the negation of `magnitude` into `T`.
+fn clamp_negated<T: SaturatingFrom<i128>>(magnitude: u128) -> T {
+    T::saturating_from(i128::try_from(magnitude).map_or(i128::MIN, |m| -m))
+}
+
+/// Accepts integers of any width and sign as well as integral floats, which
+/// is how some formats represent integers that do not fit in 64 bits. Floats
+/// too imprecise to tell which value of `T` they stand for are rejected. With
+/// `strings` set it also accepts saturating-parsed strings.
+struct LenientVisitor<T, P> {
+    strings: bool,
+    marker: PhantomData<(T, P)>,
+}
+
+impl<T, P> LenientVisitor<T, P> {
+    fn new(strings: bool) -> Self {
+        Self {
+            strings,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T: ClampFromAny + HasSaturatingParse, P: OverflowPolicy> Visitor<'de>
+    for LenientVisitor<T, P>
+{
+    type Value = Number<T, P>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.strings {
+            f.write_str("an integer or a string containing an integer")
+        } else {
+            f.write_str("an integer")
+        }
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Number::from(T::saturating_from(v)))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Number::from(T::saturating_from(v)))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        Ok(Number::from(T::saturating_from(v)))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        Ok(Number::from(T::saturating_from(v)))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        // Rejects NaN, infinities and fractions.
+        if v % 1.0 != 0.0 {
+            return Err(E::invalid_value(de::Unexpected::Float(v), &self));
+        }
+        // Above 2^53 a float stands for every integer that rounds to it, so
+        // it is only accepted if all of them clamp to the same value, i.e. if
+        // they lie beyond the same bound of `T`.
+        let (low, high) = if v.abs() <= 9_007_199_254_740_992.0 {
+            (v.abs() as u128, v.abs() as u128)
+        } else {
+            rounding_interval(v.abs())
+        };
+        let (low, high): (T, T) = if v < 0.0 {
+            (clamp_negated(high), clamp_negated(low))
+        } else {
+            (T::saturating_from(low), T::saturating_from(high))
+        };
+        if low == high {
+            Ok(Number::from(low))
+        } else {
+            Err(E::invalid_value(de::Unexpected::Float(v), &self))
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        if !self.strings {
+            return Err(E::invalid_type(de::Unexpected::Str(v), &self));
+        }
+        T::do_parse(v, true).map(Number::from).map_err(E::custom)
+    }
+}
+
+/// Deserializes any integer, clamping it to the range of `T`:
+///
+/// ```
+/// # use saturating_numbers::{serde_support::clamped, SaturatingU8};
+/// let mut json = serde_json::Deserializer::from_str("-20");
+/// let value: SaturatingU8 = clamped::deserialize(&mut json).unwrap();
+/// assert_eq!(value, SaturatingU8::from(0));
+/// ```
+pub mod clamped {
+    use super::*;
+
+    pub fn serialize<T: Serialize, P: OverflowPolicy, S: Serializer>(
+        value: &Number<T, P>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, P, D>(deserializer: D) -> Result<Number<T, P>, D::Error>
+    where
+        T: ClampFromAny + HasSaturatingParse,
+        P: OverflowPolicy,
+        D: Deserializer<'de>,
+    {
+        T::deserialize_clamped(deserializer, LenientVisitor::new(false))
+    }
+}
+
+/// Serializes as a decimal string. Deserializing accepts such strings, parsed
+/// with saturation under every policy, as well as plain integers, which are
+/// clamped.
+pub mod string {
+    use super::*;
+
+    pub fn serialize<T: fmt::Display, P: OverflowPolicy, S: Serializer>(
+        value: &Number<T, P>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if value.1.is_poisoned() {
+            return Err(ser::Error::custom("cannot serialize a poisoned number"));
+        }
+        serializer.collect_str(&value.0)
+    }
+
+    pub fn deserialize<'de, T, P, D>(deserializer: D) -> Result<Number<T, P>, D::Error>
+    where
+        T: ClampFromAny + HasSaturatingParse,
+        P: OverflowPolicy,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(LenientVisitor::new(true))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        Checked, SaturatingI128, SaturatingI16, SaturatingI64, SaturatingNumber, SaturatingU128,
+        SaturatingU32, SaturatingU64, SaturatingU8,
+    };
+
+    fn clamped_from<T: ClampFromAny + HasSaturatingParse>(
+        json: &str,
+    ) -> serde_json::Result<SaturatingNumber<T>> {
+        clamped::deserialize(&mut serde_json::Deserializer::from_str(json))
+    }
+
+    fn string_from<T: ClampFromAny + HasSaturatingParse>(
+        json: &str,
+    ) -> serde_json::Result<SaturatingNumber<T>> {
+        string::deserialize(&mut serde_json::Deserializer::from_str(json))
+    }
+
+    #[test]
+    fn test_transparent() {
+        let x = SaturatingU64::from(42);
+        assert_eq!(serde_json::to_string(&x).unwrap(), "42");
+        assert_eq!(serde_json::from_str::<SaturatingU64>("42").unwrap(), x);
+        assert!(serde_json::from_str::<SaturatingU8>("256").is_err());
+        assert!(serde_json::from_str::<SaturatingU8>("-1").is_err());
+    }
+
+    #[test]
+    fn test_other_policies() {
+        type Gas = Number<u8, Checked>;
+        let gas: Gas = serde_json::from_str("200").unwrap();
+        assert_eq!(serde_json::to_string(&gas).unwrap(), "200");
+        assert!(serde_json::to_string(&(gas + 100)).is_err());
+        let clamped: Gas =
+            clamped::deserialize(&mut serde_json::Deserializer::from_str("300")).unwrap();
+        assert_eq!(clamped.checked(), Some(u8::MAX));
+    }
+
+    #[test]
+    fn test_clamped() {
+        assert_eq!(
+            clamped_from::<u8>("256").unwrap(),
+            SaturatingU8::from(u8::MAX)
+        );
+        assert_eq!(clamped_from::<u8>("-1").unwrap(), SaturatingU8::from(0));
+        assert_eq!(clamped_from::<u8>("17").unwrap(), SaturatingU8::from(17));
+        assert_eq!(
+            clamped_from::<i16>("-99999").unwrap(),
+            SaturatingI16::from(i16::MIN)
+        );
+        assert_eq!(
+            clamped_from::<u64>("99999999999999999999").unwrap(),
+            SaturatingU64::from(u64::MAX)
+        );
+        assert_eq!(
+            clamped_from::<u32>("1e12").unwrap(),
+            SaturatingU32::from(u32::MAX)
+        );
+        assert_eq!(
+            clamped_from::<u32>("-1e12").unwrap(),
+            SaturatingU32::from(0)
+        );
+        assert!(clamped_from::<u32>("1.5").is_err());
+        assert_eq!(
+            clamped_from::<i64>("-1e30").unwrap(),
+            SaturatingI64::from(i64::MIN)
+        );
+        assert!(clamped_from::<u32>("\"5\"").is_err());
+        assert!(clamped_from::<u32>("null").is_err());
+
+        let mut out = Vec::new();
+        clamped::serialize(
+            &SaturatingU32::from(7),
+            &mut serde_json::Serializer::new(&mut out),
+        )
+        .unwrap();
+        assert_eq!(out, b"7");
+    }
+
+    #[test]
+    fn test_string_u128() {
+        let max = SaturatingU128::from(u128::MAX);
+        let mut out = Vec::new();
+        string::serialize(&max, &mut serde_json::Serializer::new(&mut out)).unwrap();
+        assert_eq!(out, b"\"340282366920938463463374607431768211455\"");
+        assert_eq!(
+            string_from::<u128>("\"340282366920938463463374607431768211455\"").unwrap(),
+            max
+        );
+        assert_eq!(
+            string_from::<u128>("\"999999999999999999999999999999999999999999\"").unwrap(),
+            max
+        );
+        assert_eq!(
+            string_from::<u128>("\"-3\"").unwrap(),
+            SaturatingU128::from(0)
+        );
+        assert_eq!(string_from::<u128>("12").unwrap(), SaturatingU128::from(12));
+        assert!(string_from::<u128>("\"twelve\"").is_err());
+    }
+
+    #[test]
+    fn test_wide_floats_are_not_rounded() {
+        // The 128-bit types ask for a 128-bit integer, which serde_json reads
+        // exactly.
+        assert_eq!(
+            clamped_from::<u128>("18446744073709551616").unwrap(),
+            SaturatingU128::from(18_446_744_073_709_551_616)
+        );
+        assert_eq!(
+            clamped_from::<u128>("340282366920938463463374607431768211455").unwrap(),
+            SaturatingU128::from(u128::MAX)
+        );
+        assert_eq!(
+            clamped_from::<i128>("-18446744073709551617").unwrap(),
+            SaturatingI128::from(-18_446_744_073_709_551_617)
+        );
+        assert_eq!(
+            clamped_from::<i128>("-170141183460469231731687303715884105728").unwrap(),
+            SaturatingI128::from(i128::MIN)
+        );
+        // The string encoding has to accept strings too, so serde_json hands
+        // plain integers above `u64::MAX` over as floats.
+        assert!(string_from::<u128>("18446744073709551617").is_err());
+        assert_eq!(
+            string_from::<u128>("\"18446744073709551617\"").unwrap(),
+            SaturatingU128::from(18_446_744_073_709_551_617)
+        );
+        // Clamping is exact when every integer the float may stand for is
+        // out of range.
+        assert_eq!(
+            clamped_from::<u64>("18446744073709600000").unwrap(),
+            SaturatingU64::from(u64::MAX)
+        );
+        // 2^64 + 1 becomes the float 2^64, which may stand for `u64::MAX`.
+        assert!(clamped_from::<u64>("18446744073709551617").is_err());
+        assert_eq!(
+            string_from::<u128>("1e40").unwrap(),
+            SaturatingU128::from(u128::MAX)
+        );
+        assert_eq!(
+            string_from::<i128>("-1e40").unwrap(),
+            SaturatingI128::from(i128::MIN)
+        );
+        assert_eq!(
+            clamped_from::<u32>("9007199254740992").unwrap(),
+            SaturatingU32::from(u32::MAX)
+        );
+    }
+}
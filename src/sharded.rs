@@ -0,0 +1,155 @@
+//! A saturating counter spread across cache-line-padded shards, for hot paths
+//! where a single `AtomicSaturatingU64` would contend.
+
+use crate::{AtomicSaturatingU64, SaturatingU64};
+use std::{
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+/// Keeps each shard on its own cache line (two on platforms that prefetch
+/// adjacent lines) so that threads incrementing different shards do not
+/// contend.
+#[repr(align(128))]
+#[derive(Default)]
+struct Shard(AtomicSaturatingU64);
+
+/// A monotonic saturating counter. Each thread increments its own shard and
+/// `sum` combines the shards with saturating addition, so the total reads as
+/// `MAX` once any shard or the sum of all shards reaches it, just as a single
+/// counter would.
+pub struct ShardedSaturatingCounter {
+    shards: Box<[Shard]>,
+}
+
+/// Index of the shard the current thread increments, modulo the shard count.
+fn thread_shard() -> usize {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    thread_local! {
+        static SHARD: usize = NEXT.fetch_add(1, Ordering::Relaxed);
+    }
+    SHARD.with(|shard| *shard)
+}
+
+impl ShardedSaturatingCounter {
+    /// Creates a counter with one shard per available CPU.
+    pub fn new() -> Self {
+        let shards = thread::available_parallelism().map_or(4, |n| n.get());
+        Self::with_shards(shards)
+    }
+
+    /// Creates a counter with `shards` shards, or one if `shards` is zero.
+    pub fn with_shards(shards: usize) -> Self {
+        Self {
+            shards: (0..shards.max(1)).map(|_| Shard::default()).collect(),
+        }
+    }
+
+    pub fn add(&self, value: SaturatingU64) {
+        let shard = &self.shards[thread_shard() % self.shards.len()];
+        shard.0.fetch_add(value, Ordering::Relaxed);
+    }
+
+    pub fn increment(&self) {
+        self.add(SaturatingU64::from(1))
+    }
+
+    /// Returns the saturating sum of all shards. Concurrent increments may or
+    /// may not be included. Like loading a single counter, this never panics
+    /// under `strict-debug`.
+    pub fn sum(&self) -> SaturatingU64 {
+        let total = self.shards.iter().fold(0u64, |total, shard| {
+            total.saturating_add(shard.0.load(Ordering::Relaxed).get())
+        });
+        SaturatingU64::from(total)
+    }
+}
+
+impl Default for ShardedSaturatingCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ShardedSaturatingCounter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.sum(), f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::Arc;
+
+    fn hammer(counter: &Arc<ShardedSaturatingCounter>, threads: usize, adds: u64, value: u64) {
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                let counter = Arc::clone(counter);
+                thread::spawn(move || {
+                    for _ in 0..adds {
+                        counter.add(SaturatingU64::from(value));
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_single_thread() {
+        let counter = ShardedSaturatingCounter::with_shards(0);
+        counter.increment();
+        counter.add(SaturatingU64::from(41));
+        assert_eq!(counter.sum(), SaturatingU64::from(42));
+        assert_eq!(format!("{:?}", counter), "42");
+        counter.add(SaturatingU64::from(u64::MAX));
+        assert_eq!(counter.sum(), SaturatingU64::from(u64::MAX));
+    }
+
+    #[test]
+    fn test_concurrent_sum_is_exact() {
+        let counter = Arc::new(ShardedSaturatingCounter::with_shards(4));
+        hammer(&counter, 16, 10_000, 3);
+        assert_eq!(counter.sum(), SaturatingU64::from(16 * 10_000 * 3));
+    }
+
+    #[test]
+    fn test_concurrent_total_saturates() {
+        // No single shard can reach `MAX`, but their sum does.
+        let counter = Arc::new(ShardedSaturatingCounter::with_shards(8));
+        hammer(&counter, 16, 1_000, u64::MAX / 4_000);
+        assert_eq!(counter.sum(), SaturatingU64::from(u64::MAX));
+        hammer(&counter, 16, 100, 1);
+        assert_eq!(counter.sum(), SaturatingU64::from(u64::MAX));
+    }
+
+    #[test]
+    fn test_concurrent_shard_saturates() {
+        let counter = Arc::new(ShardedSaturatingCounter::with_shards(8));
+        hammer(&counter, 16, 1_000, u64::MAX / 2);
+        assert_eq!(counter.sum(), SaturatingU64::from(u64::MAX));
+    }
+
+    #[test]
+    fn test_reads_are_monotonic_under_contention() {
+        let counter = Arc::new(ShardedSaturatingCounter::with_shards(4));
+        let reader = {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                let mut last = SaturatingU64::from(0);
+                for _ in 0..10_000 {
+                    let current = counter.sum();
+                    assert!(current >= last);
+                    last = current;
+                }
+            })
+        };
+        hammer(&counter, 8, 10_000, u64::MAX / 50_000);
+        reader.join().unwrap();
+        assert_eq!(counter.sum(), SaturatingU64::from(u64::MAX));
+    }
+}
@@ -0,0 +1,247 @@
+//! Interoperability with `core::num::Saturating<T>`.
+
+use crate::{AddPolicy, MulPolicy, Saturate, SaturatingNumber, SubPolicy};
+use core::{
+    cmp::Ordering,
+    num::Saturating,
+    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+impl<T> From<Saturating<T>> for SaturatingNumber<T> {
+    fn from(input: Saturating<T>) -> Self {
+        Self(input.0, Saturate)
+    }
+}
+
+impl<T> From<SaturatingNumber<T>> for Saturating<T> {
+    fn from(input: SaturatingNumber<T>) -> Self {
+        Saturating(input.0)
+    }
+}
+
+// Mixed operators return the type of their left operand.
+
+macro_rules! impl_std_binop {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, $Policy:ident, $apply_assign:ident) => {
+        impl<T: $Op<Output = T>> $Op<Saturating<T>> for SaturatingNumber<T>
+        where
+            Saturate: $Policy<T>,
+        {
+            type Output = Self;
+
+            fn $op(mut self, rhs: Saturating<T>) -> Self {
+                Saturate::$apply_assign(&mut self.0, &rhs.0);
+                self
+            }
+        }
+
+        impl<T: $Op<Output = T>> $OpAssign<Saturating<T>> for SaturatingNumber<T>
+        where
+            Saturate: $Policy<T>,
+        {
+            fn $op_assign(&mut self, rhs: Saturating<T>) {
+                Saturate::$apply_assign(&mut self.0, &rhs.0);
+            }
+        }
+
+        impl<T: $Op<Output = T>> $Op<SaturatingNumber<T>> for Saturating<T>
+        where
+            Saturate: $Policy<T>,
+        {
+            type Output = Self;
+
+            fn $op(mut self, rhs: SaturatingNumber<T>) -> Self {
+                Saturate::$apply_assign(&mut self.0, &rhs.0);
+                self
+            }
+        }
+
+        impl<T: $Op<Output = T>> $OpAssign<SaturatingNumber<T>> for Saturating<T>
+        where
+            Saturate: $Policy<T>,
+        {
+            fn $op_assign(&mut self, rhs: SaturatingNumber<T>) {
+                Saturate::$apply_assign(&mut self.0, &rhs.0);
+            }
+        }
+    };
+}
+
+impl_std_binop!(
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    AddPolicy,
+    policy_add_assign
+);
+impl_std_binop!(
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    SubPolicy,
+    policy_sub_assign
+);
+impl_std_binop!(
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    MulPolicy,
+    policy_mul_assign
+);
+
+impl<T: PartialEq> PartialEq<Saturating<T>> for SaturatingNumber<T> {
+    fn eq(&self, other: &Saturating<T>) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: PartialEq> PartialEq<SaturatingNumber<T>> for Saturating<T> {
+    fn eq(&self, other: &SaturatingNumber<T>) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: PartialOrd> PartialOrd<Saturating<T>> for SaturatingNumber<T> {
+    fn partial_cmp(&self, other: &Saturating<T>) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<T: PartialOrd> PartialOrd<SaturatingNumber<T>> for Saturating<T> {
+    fn partial_cmp(&self, other: &SaturatingNumber<T>) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{SaturatingI16, SaturatingU64};
+
+    #[test]
+    fn test_conversions() {
+        let ours = SaturatingU64::from(Saturating(5u64));
+        assert_eq!(ours, SaturatingU64::from(5));
+        let theirs: Saturating<u64> = ours.into();
+        assert_eq!(theirs, Saturating(5));
+    }
+
+    #[test]
+    fn test_mixed_operators() {
+        let ours = SaturatingU64::from(u64::MAX - 1);
+        let theirs = Saturating(10u64);
+        assert_eq!(ours + theirs, SaturatingU64::from(u64::MAX));
+        assert_eq!(theirs + ours, Saturating(u64::MAX));
+        assert_eq!(theirs - ours, Saturating(0));
+        assert_eq!(ours * theirs, SaturatingU64::from(u64::MAX));
+
+        let mut x = SaturatingI16::from(i16::MIN);
+        x -= Saturating(1);
+        assert_eq!(x, SaturatingI16::from(i16::MIN));
+        let mut y = Saturating(100i16);
+        y *= SaturatingI16::from(1000);
+        assert_eq!(y, Saturating(i16::MAX));
+        y += SaturatingI16::from(1);
+        y -= SaturatingI16::from(i16::MAX);
+        assert_eq!(y, Saturating(0));
+    }
+
+    #[test]
+    fn test_mixed_comparisons() {
+        assert_eq!(SaturatingU64::from(3), Saturating(3u64));
+        assert_eq!(Saturating(3u64), SaturatingU64::from(3));
+        assert!(SaturatingU64::from(3) < Saturating(4u64));
+        assert!(Saturating(5u64) > SaturatingU64::from(4));
+    }
+
+    /// Checks every operation both types support against each other for the
+    /// given operands.
+    macro_rules! differential {
+        ($t:ty, $values:expr) => {{
+            let values: Vec<$t> = $values;
+            for &a in &values {
+                for &b in &values {
+                    let (x, y) = (SaturatingNumber::from(a), SaturatingNumber::from(b));
+                    let (sx, sy) = (Saturating(a), Saturating(b));
+                    assert_eq!(x + y, sx + sy, "{} + {}", a, b);
+                    assert_eq!(x - y, sx - sy, "{} - {}", a, b);
+                    assert_eq!(x * y, sx * sy, "{} * {}", a, b);
+                    if b != 0 {
+                        assert_eq!(x / y, sx / sy, "{} / {}", a, b);
+                        // `Saturating` does not guard `MIN % -1`.
+                        if a.checked_rem(b).is_some() {
+                            assert_eq!(x % y, sx % sy, "{} % {}", a, b);
+                        }
+                    }
+                }
+                for exp in 0..=<$t>::BITS + 1 {
+                    let x = SaturatingNumber::from(a);
+                    assert_eq!(x.pow(exp), Saturating(a).pow(exp), "{} ^ {}", a, exp);
+                }
+            }
+        }};
+    }
+
+    macro_rules! differential_signed {
+        ($t:ty, $values:expr) => {{
+            differential!($t, $values.clone());
+            let values: Vec<$t> = $values;
+            for &a in &values {
+                let x = SaturatingNumber::from(a);
+                assert_eq!(-x, -Saturating(a), "-{}", a);
+                assert_eq!(x.abs(), Saturating(a).abs(), "|{}|", a);
+            }
+        }};
+    }
+
+    macro_rules! edge_values {
+        ($t:ty) => {{
+            let mut values: Vec<$t> = vec![
+                <$t>::MIN,
+                <$t>::MIN + 1,
+                <$t>::MIN / 2,
+                0,
+                1,
+                2,
+                3,
+                7,
+                <$t>::MAX / 3,
+                <$t>::MAX / 2,
+                <$t>::MAX / 2 + 1,
+                <$t>::MAX - 1,
+                <$t>::MAX,
+            ];
+            if <$t>::MIN != 0 {
+                values.extend([-1, -2, -3].iter().map(|&v: &i8| v as $t));
+            }
+            values
+        }};
+    }
+
+    #[test]
+    fn test_against_std_exhaustive_8_bit() {
+        differential!(u8, (u8::MIN..=u8::MAX).collect());
+        differential_signed!(i8, (i8::MIN..=i8::MAX).collect::<Vec<i8>>());
+    }
+
+    #[test]
+    fn test_against_std_unsigned() {
+        differential!(u16, edge_values!(u16));
+        differential!(u32, edge_values!(u32));
+        differential!(u64, edge_values!(u64));
+        differential!(u128, edge_values!(u128));
+        differential!(usize, edge_values!(usize));
+    }
+
+    #[test]
+    fn test_against_std_signed() {
+        differential_signed!(i16, edge_values!(i16));
+        differential_signed!(i32, edge_values!(i32));
+        differential_signed!(i64, edge_values!(i64));
+        differential_signed!(i128, edge_values!(i128));
+        differential_signed!(isize, edge_values!(isize));
+    }
+}
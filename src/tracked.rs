@@ -0,0 +1,160 @@
+//! A wrapper that remembers whether saturation happened anywhere in the chain
+//! of operations that produced a value.
+
+use crate::{
+    HasSaturatingAdd, HasSaturatingMul, HasSaturatingSub, Number, Saturate, SaturatingNumber,
+    Saturation,
+};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+/// A saturating number with a sticky flag that is set once any operation
+/// contributing to it was clamped. The flag is OR-ed across operands, so a
+/// result is marked as saturated if either input was.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Tracked<N> {
+    value: N,
+    saturated: bool,
+}
+
+impl<N> Tracked<N> {
+    pub fn new(value: N) -> Self {
+        Self {
+            value,
+            saturated: false,
+        }
+    }
+
+    pub fn get(&self) -> &N {
+        &self.value
+    }
+
+    pub fn into_inner(self) -> N {
+        self.value
+    }
+
+    /// Returns true if this value, or any value it was computed from, was
+    /// clamped and therefore may have lost precision.
+    pub fn was_saturated(&self) -> bool {
+        self.saturated
+    }
+}
+
+impl<T> From<SaturatingNumber<T>> for Tracked<SaturatingNumber<T>> {
+    fn from(input: SaturatingNumber<T>) -> Self {
+        Self::new(input)
+    }
+}
+
+impl<T> From<T> for Tracked<SaturatingNumber<T>> {
+    fn from(input: T) -> Self {
+        Self::new(Number(input, Saturate))
+    }
+}
+
+impl<T: Add<Output = T> + HasSaturatingAdd> Add for Tracked<SaturatingNumber<T>> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let (value, clamped) = self.value.0.do_saturating_add_reporting(&rhs.value.0);
+        Self {
+            value: Number(value, Saturate),
+            saturated: self.saturated || rhs.saturated || clamped != Saturation::None,
+        }
+    }
+}
+
+impl<T: Add<Output = T> + HasSaturatingAdd> AddAssign for Tracked<SaturatingNumber<T>> {
+    fn add_assign(&mut self, rhs: Self) {
+        let (value, clamped) = self.value.0.do_saturating_add_reporting(&rhs.value.0);
+        self.value.0 = value;
+        self.saturated |= rhs.saturated || clamped != Saturation::None;
+    }
+}
+
+impl<T: Sub<Output = T> + HasSaturatingSub> Sub for Tracked<SaturatingNumber<T>> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let (value, clamped) = self.value.0.do_saturating_sub_reporting(&rhs.value.0);
+        Self {
+            value: Number(value, Saturate),
+            saturated: self.saturated || rhs.saturated || clamped != Saturation::None,
+        }
+    }
+}
+
+impl<T: Sub<Output = T> + HasSaturatingSub> SubAssign for Tracked<SaturatingNumber<T>> {
+    fn sub_assign(&mut self, rhs: Self) {
+        let (value, clamped) = self.value.0.do_saturating_sub_reporting(&rhs.value.0);
+        self.value.0 = value;
+        self.saturated |= rhs.saturated || clamped != Saturation::None;
+    }
+}
+
+impl<T: Mul<Output = T> + HasSaturatingMul> Mul for Tracked<SaturatingNumber<T>> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let (value, clamped) = self.value.0.do_saturating_mul_reporting(&rhs.value.0);
+        Self {
+            value: Number(value, Saturate),
+            saturated: self.saturated || rhs.saturated || clamped != Saturation::None,
+        }
+    }
+}
+
+impl<T: Mul<Output = T> + HasSaturatingMul> MulAssign for Tracked<SaturatingNumber<T>> {
+    fn mul_assign(&mut self, rhs: Self) {
+        let (value, clamped) = self.value.0.do_saturating_mul_reporting(&rhs.value.0);
+        self.value.0 = value;
+        self.saturated |= rhs.saturated || clamped != Saturation::None;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{SaturatingI32, SaturatingU64};
+
+    type TrackedU64 = Tracked<SaturatingU64>;
+
+    #[test]
+    fn test_exact_results_are_not_flagged() {
+        let total =
+            TrackedU64::from(2) * TrackedU64::from(3) + TrackedU64::from(4) - TrackedU64::from(10);
+        assert_eq!(*total.get(), SaturatingU64::from(0));
+        assert!(!total.was_saturated());
+    }
+
+    #[test]
+    fn test_flag_is_set_and_sticky() {
+        let overflowed = TrackedU64::from(u64::MAX) + TrackedU64::from(1);
+        assert!(overflowed.was_saturated());
+        assert_eq!(overflowed.into_inner(), SaturatingU64::from(u64::MAX));
+
+        // Bringing the value back into range does not clear the flag.
+        let back = overflowed - TrackedU64::from(u64::MAX);
+        assert_eq!(*back.get(), SaturatingU64::from(0));
+        assert!(back.was_saturated());
+
+        let underflowed = TrackedU64::from(1) - TrackedU64::from(2);
+        assert!(underflowed.was_saturated());
+        let mul = Tracked::<SaturatingI32>::from(i32::MIN) * Tracked::from(2);
+        assert!(mul.was_saturated());
+    }
+
+    #[test]
+    fn test_flag_is_ored_across_operands() {
+        let clean = TrackedU64::from(5);
+        let dirty = TrackedU64::from(u64::MAX) * TrackedU64::from(2);
+        assert!((clean + dirty).was_saturated());
+        assert!((dirty + clean).was_saturated());
+        let mut acc = TrackedU64::from(0);
+        acc += clean;
+        assert!(!acc.was_saturated());
+        acc *= dirty;
+        assert!(acc.was_saturated());
+        acc -= clean;
+        assert!(acc.was_saturated());
+    }
+}
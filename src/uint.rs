@@ -0,0 +1,452 @@
+//! Unsigned integers of any width from 1 to 128 bits, e.g. 24-bit counters in
+//! a wire format:
+//!
+//! ```
+//! use saturating_numbers::{SaturatingU24, Saturation, Uint};
+//!
+//! let count = SaturatingU24::from(Uint::clamped(16_000_000));
+//! let (count, saturation) = count.add_reporting(SaturatingU24::from(Uint::clamped(1_000_000)));
+//! assert_eq!(count, SaturatingU24::from(Uint::MAX));
+//! assert_eq!(saturation, Saturation::Upper);
+//! assert_eq!(Uint::<24>::MAX.to_packed(), 0xff_ffff_u32);
+//! assert!(Uint::<24>::from_packed(0x100_0000).is_none());
+//! ```
+//!
+//! Stable Rust cannot compute a type from a const generic, so `UintStorage`
+//! maps each supported width to the smallest primitive that holds it.
+
+use crate::{
+    HasSaturatingAdd, HasSaturatingBounds, HasSaturatingMul, HasSaturatingParse,
+    HasSaturatingProduct, HasSaturatingSub, HasSaturatingSum, HasWrappingAdd, HasWrappingMul,
+    HasWrappingSub, ParseErrorKind, ParseSaturatingError, Saturation,
+};
+use core::{
+    convert::TryFrom,
+    fmt,
+    hash::Hash,
+    ops::{Add, Mul, Sub},
+};
+
+/// Marker for a bit width, used to look up its `UintStorage`.
+pub struct Bits<const BITS: u32>;
+
+/// The storage of `Uint<BITS>`. Implemented for `Bits<1>` through `Bits<128>`.
+pub trait UintStorage {
+    type Repr: Copy + Ord + Hash + Default + fmt::Debug + fmt::Display + Into<u128> + TryFrom<u128>;
+
+    const ZERO: Self::Repr;
+    /// `2^BITS - 1`.
+    const MAX: Self::Repr;
+}
+
+macro_rules! uint_storage {
+    ($($t:ty: $($bits:literal),*;)*) => {
+        $($(
+            impl UintStorage for Bits<$bits> {
+                type Repr = $t;
+
+                const ZERO: $t = 0;
+                const MAX: $t = <$t>::MAX >> (<$t>::BITS - $bits);
+            }
+        )*)*
+    };
+}
+
+uint_storage! {
+    u8: 1, 2, 3, 4, 5, 6, 7, 8;
+    u16: 9, 10, 11, 12, 13, 14, 15, 16;
+    u32: 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32;
+    u64: 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54,
+        55, 56, 57, 58, 59, 60, 61, 62, 63, 64;
+    u128: 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86,
+        87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107,
+        108, 109, 110, 111, 112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125,
+        126, 127, 128;
+}
+
+type Repr<const BITS: u32> = <Bits<BITS> as UintStorage>::Repr;
+
+/// An unsigned integer in `0..=2^BITS - 1`.
+#[derive(PartialOrd, Ord, PartialEq, Eq, Hash, Copy, Clone, Default)]
+pub struct Uint<const BITS: u32>(Repr<BITS>)
+where
+    Bits<BITS>: UintStorage;
+
+impl<const BITS: u32> Uint<BITS>
+where
+    Bits<BITS>: UintStorage,
+{
+    pub const MIN: Self = Self(<Bits<BITS> as UintStorage>::ZERO);
+    pub const MAX: Self = Self(<Bits<BITS> as UintStorage>::MAX);
+
+    /// Returns `None` if `value` has bits set above the lowest `BITS`.
+    pub fn from_packed(value: Repr<BITS>) -> Option<Self> {
+        if value <= Self::MAX.0 {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+
+    /// Clamps `value` to `MAX`.
+    pub fn clamped(value: Repr<BITS>) -> Self {
+        Self(value.min(Self::MAX.0))
+    }
+
+    /// Returns the value in its storage primitive, with all bits above the
+    /// lowest `BITS` clear.
+    pub fn to_packed(self) -> Repr<BITS> {
+        self.0
+    }
+
+    /// Converts the exact result of an operation back, or reports it as
+    /// having overflowed in the given direction.
+    fn from_wide(value: Option<u128>, overflow: Saturation) -> (Self, Saturation) {
+        let max: u128 = Self::MAX.0.into();
+        match value.filter(|&value| value <= max) {
+            Some(value) => match Repr::<BITS>::try_from(value) {
+                Ok(value) => (Self(value), Saturation::None),
+                Err(_) => unreachable!("value fits in BITS bits"),
+            },
+            None if overflow == Saturation::Lower => (Self::MIN, overflow),
+            None => (Self::MAX, overflow),
+        }
+    }
+
+    /// Keeps the low `BITS` bits of `value`.
+    fn from_wide_wrapping(value: u128) -> Self {
+        let max: u128 = Self::MAX.0.into();
+        Self::from_wide(Some(value & max), Saturation::None).0
+    }
+
+    fn wide(self) -> u128 {
+        self.0.into()
+    }
+}
+
+// The plain operators panic on overflow, as for `U256`.
+
+impl<const BITS: u32> Add for Uint<BITS>
+where
+    Bits<BITS>: UintStorage,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let (sum, saturation) = self.do_saturating_add_reporting(&rhs);
+        assert!(
+            saturation == Saturation::None,
+            "attempt to add with overflow"
+        );
+        sum
+    }
+}
+
+impl<const BITS: u32> Sub for Uint<BITS>
+where
+    Bits<BITS>: UintStorage,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let (diff, saturation) = self.do_saturating_sub_reporting(&rhs);
+        assert!(
+            saturation == Saturation::None,
+            "attempt to subtract with overflow"
+        );
+        diff
+    }
+}
+
+impl<const BITS: u32> Mul for Uint<BITS>
+where
+    Bits<BITS>: UintStorage,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let (product, saturation) = self.do_saturating_mul_reporting(&rhs);
+        assert!(
+            saturation == Saturation::None,
+            "attempt to multiply with overflow"
+        );
+        product
+    }
+}
+
+impl<const BITS: u32> HasSaturatingAdd for Uint<BITS>
+where
+    Bits<BITS>: UintStorage,
+{
+    fn do_saturating_add(&self, rhs: &Self) -> Self {
+        self.do_saturating_add_reporting(rhs).0
+    }
+
+    fn do_saturating_add_reporting(&self, rhs: &Self) -> (Self, Saturation) {
+        Self::from_wide(self.wide().checked_add(rhs.wide()), Saturation::Upper)
+    }
+
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<const BITS: u32> HasSaturatingSub for Uint<BITS>
+where
+    Bits<BITS>: UintStorage,
+{
+    fn do_saturating_sub(&self, rhs: &Self) -> Self {
+        self.do_saturating_sub_reporting(rhs).0
+    }
+
+    fn do_saturating_sub_reporting(&self, rhs: &Self) -> (Self, Saturation) {
+        Self::from_wide(self.wide().checked_sub(rhs.wide()), Saturation::Lower)
+    }
+
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<const BITS: u32> HasSaturatingMul for Uint<BITS>
+where
+    Bits<BITS>: UintStorage,
+{
+    fn do_saturating_mul(&self, rhs: &Self) -> Self {
+        self.do_saturating_mul_reporting(rhs).0
+    }
+
+    fn do_saturating_mul_reporting(&self, rhs: &Self) -> (Self, Saturation) {
+        Self::from_wide(self.wide().checked_mul(rhs.wide()), Saturation::Upper)
+    }
+
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+// Arithmetic modulo 2^128 agrees with arithmetic modulo 2^BITS in the low
+// `BITS` bits.
+
+impl<const BITS: u32> HasWrappingAdd for Uint<BITS>
+where
+    Bits<BITS>: UintStorage,
+{
+    fn do_wrapping_add(&self, rhs: &Self) -> Self {
+        Self::from_wide_wrapping(self.wide().wrapping_add(rhs.wide()))
+    }
+}
+
+impl<const BITS: u32> HasWrappingSub for Uint<BITS>
+where
+    Bits<BITS>: UintStorage,
+{
+    fn do_wrapping_sub(&self, rhs: &Self) -> Self {
+        Self::from_wide_wrapping(self.wide().wrapping_sub(rhs.wide()))
+    }
+}
+
+impl<const BITS: u32> HasWrappingMul for Uint<BITS>
+where
+    Bits<BITS>: UintStorage,
+{
+    fn do_wrapping_mul(&self, rhs: &Self) -> Self {
+        Self::from_wide_wrapping(self.wide().wrapping_mul(rhs.wide()))
+    }
+}
+
+impl<const BITS: u32> HasSaturatingSum for Uint<BITS>
+where
+    Bits<BITS>: UintStorage,
+{
+    fn zero() -> Self {
+        Self::MIN
+    }
+
+    fn absorbs_add(&self) -> bool {
+        *self == Self::MAX
+    }
+}
+
+impl<const BITS: u32> HasSaturatingProduct for Uint<BITS>
+where
+    Bits<BITS>: UintStorage,
+{
+    fn one() -> Self {
+        Self::from_wide(Some(1), Saturation::Upper).0
+    }
+
+    fn absorbs_mul(&self) -> bool {
+        *self == Self::MIN
+    }
+}
+
+impl<const BITS: u32> HasSaturatingBounds for Uint<BITS>
+where
+    Bits<BITS>: UintStorage,
+{
+    fn is_at_bound(&self) -> bool {
+        *self == Self::MAX
+    }
+}
+
+impl<const BITS: u32> HasSaturatingParse for Uint<BITS>
+where
+    Bits<BITS>: UintStorage,
+{
+    fn do_parse(src: &str, saturate: bool) -> Result<Self, ParseSaturatingError> {
+        // Anything beyond `u128` is beyond `MAX` too, so `u128` has already
+        // reported or clamped it.
+        let value = u128::do_parse(src, saturate)?;
+        match Self::from_wide(Some(value), Saturation::Upper) {
+            (value, Saturation::None) => Ok(value),
+            (value, _) if saturate => Ok(value),
+            _ => Err(ParseErrorKind::PosOverflow.into()),
+        }
+    }
+}
+
+impl<const BITS: u32> fmt::Display for Uint<BITS>
+where
+    Bits<BITS>: UintStorage,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const BITS: u32> fmt::Debug for Uint<BITS>
+where
+    Bits<BITS>: UintStorage,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::XorShift;
+    use crate::{SaturatingNumber, SaturatingU24, SaturatingU48, SaturatingUint};
+
+    /// The reference model computes exactly in `u128` and clamps afterwards.
+    fn model_values(bits: u32) -> Vec<u128> {
+        let max = u128::MAX >> (128 - bits);
+        let root = 1u128 << (bits / 2);
+        let mut values = vec![
+            0,
+            1,
+            2,
+            3,
+            max / 2,
+            max / 2 + 1,
+            max - 1,
+            max,
+            root - 1,
+            root,
+        ];
+        let mut rng = XorShift::new(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..16 {
+            let random = u128::from(rng.next_u64()) << 64 | u128::from(rng.next_u64());
+            values.push(random & max);
+        }
+        values.retain(|&value| value <= max);
+        values
+    }
+
+    macro_rules! check_against_model {
+        ($($bits:literal),*) => {$({
+            type N = SaturatingUint<$bits>;
+            let max = u128::MAX >> (128 - $bits);
+            let make = |value: u128| -> N {
+                let packed = Repr::<$bits>::try_from(value).ok().unwrap();
+                N::from(Uint::from_packed(packed).unwrap())
+            };
+            let clamp = |value: Option<u128>| make(value.unwrap_or(max).min(max));
+            let values: Vec<u128> = if $bits <= 8 {
+                (0..=max).collect()
+            } else {
+                model_values($bits)
+            };
+            for &a in &values {
+                for &b in &values {
+                    let (x, y) = (make(a), make(b));
+                    assert_eq!(x + y, clamp(a.checked_add(b)), "{} + {}", a, b);
+                    assert_eq!(x - y, make(a.saturating_sub(b)), "{} - {}", a, b);
+                    assert_eq!(x * y, clamp(a.checked_mul(b)), "{} * {}", a, b);
+                    assert_eq!(x < y, a < b);
+                }
+                let x = make(a);
+                assert_eq!(x.to_string(), a.to_string());
+                assert_eq!(a.to_string().parse::<N>(), Ok(x));
+            }
+            assert_eq!(format!("{}0", max).parse::<N>(), Ok(make(max)));
+        })*};
+    }
+
+    #[test]
+    fn test_widths_against_model() {
+        check_against_model!(1, 3, 7, 8, 12, 24, 31, 48, 63, 64, 65, 100, 127, 128);
+    }
+
+    #[test]
+    fn test_packed_conversions() {
+        assert_eq!(Uint::<12>::MAX.to_packed(), 0xfff_u16);
+        assert_eq!(
+            Uint::<12>::from_packed(0xfff).map(Uint::to_packed),
+            Some(0xfff)
+        );
+        assert_eq!(Uint::<12>::from_packed(0x1000), None);
+        assert_eq!(Uint::<12>::clamped(0xffff), Uint::MAX);
+        assert_eq!(Uint::<48>::MAX.to_packed(), 0xffff_ffff_ffff_u64);
+        assert_eq!(Uint::<1>::MAX.to_packed(), 1_u8);
+        assert_eq!(Uint::<128>::MAX.to_packed(), u128::MAX);
+        assert_eq!(Uint::<24>::default(), Uint::MIN);
+    }
+
+    #[test]
+    fn test_aliases() {
+        let max = SaturatingU24::from(Uint::MAX);
+        let one = SaturatingU24::from(Uint::clamped(1));
+        assert_eq!(max + one, max);
+        assert_eq!(max.add_reporting(one), (max, Saturation::Upper));
+        assert_eq!(format!("{:#}", max), "16777215 (saturated)");
+        assert_eq!(format!("{:?}", one), "1");
+        let mut total = SaturatingU48::from(Uint::clamped(1 << 47));
+        total *= Uint::clamped(2);
+        assert_eq!(total, SaturatingU48::from(Uint::MAX));
+        assert_eq!(
+            vec![one, one, one].into_iter().sum::<SaturatingU24>(),
+            SaturatingU24::from(Uint::clamped(3))
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            SaturatingNumber::<Uint<24>>::parse_strict("16777216")
+                .unwrap_err()
+                .kind(),
+            ParseErrorKind::PosOverflow
+        );
+        assert_eq!(
+            SaturatingU24::parse_strict("-1").unwrap_err().kind(),
+            ParseErrorKind::NegOverflow
+        );
+        assert_eq!(
+            "-1".parse::<SaturatingU24>(),
+            Ok(SaturatingU24::from(Uint::MIN))
+        );
+        assert_eq!(
+            "0xff_ffff".parse::<SaturatingU24>(),
+            Ok(SaturatingU24::from(Uint::MAX))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn test_plain_operators_panic() {
+        let _ = Uint::<24>::MAX * Uint::clamped(2);
+    }
+}
@@ -0,0 +1,626 @@
+//! Fixed-width unsigned integers wider than `u128`, e.g. for token balances
+//! or values derived from 256-bit hashes.
+//!
+//! ```
+//! use saturating_numbers::{SaturatingU256, Saturation, U256};
+//!
+//! let balance: SaturatingU256 = "0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff".parse().unwrap();
+//! let doubled = balance * SaturatingU256::from(U256::from(2u8));
+//! assert!(doubled > balance);
+//! let (squared, saturation) = doubled.mul_reporting(doubled);
+//! assert_eq!(squared, SaturatingU256::from(U256::MAX));
+//! assert_eq!(saturation, Saturation::Upper);
+//! ```
+
+use crate::parse::{digits, split};
+use crate::{
+    HasSaturatingAdd, HasSaturatingBounds, HasSaturatingMul, HasSaturatingParse,
+    HasSaturatingProduct, HasSaturatingSub, HasSaturatingSum, HasWrappingAdd, HasWrappingMul,
+    HasWrappingSub, ParseErrorKind, ParseSaturatingError, Saturation,
+};
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Mul, Sub},
+    str,
+};
+
+fn overflowing_add<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], bool) {
+    let mut out = [0; N];
+    let mut carry = false;
+    for (i, limb) in out.iter_mut().enumerate() {
+        let (sum, c1) = a[i].overflowing_add(b[i]);
+        let (sum, c2) = sum.overflowing_add(u64::from(carry));
+        *limb = sum;
+        carry = c1 || c2;
+    }
+    (out, carry)
+}
+
+fn overflowing_sub<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], bool) {
+    let mut out = [0; N];
+    let mut borrow = false;
+    for (i, limb) in out.iter_mut().enumerate() {
+        let (diff, b1) = a[i].overflowing_sub(b[i]);
+        let (diff, b2) = diff.overflowing_sub(u64::from(borrow));
+        *limb = diff;
+        borrow = b1 || b2;
+    }
+    (out, borrow)
+}
+
+/// Schoolbook multiplication that keeps the low `N` limbs and reports whether
+/// any of the high ones would have been nonzero.
+fn overflowing_mul<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], bool) {
+    let mut out = [0; N];
+    let mut overflowed = false;
+    for (i, &x) in a.iter().enumerate() {
+        if x == 0 {
+            continue;
+        }
+        let mut carry = 0;
+        for (j, &y) in b.iter().enumerate() {
+            // At most (2^64 - 1)^2 + 2 * (2^64 - 1) = 2^128 - 1.
+            let prior = out.get(i + j).copied().unwrap_or(0);
+            let acc = u128::from(x) * u128::from(y) + u128::from(carry) + u128::from(prior);
+            match out.get_mut(i + j) {
+                Some(limb) => *limb = acc as u64,
+                None => overflowed |= acc as u64 != 0,
+            }
+            carry = (acc >> 64) as u64;
+        }
+        overflowed |= carry != 0;
+    }
+    (out, overflowed)
+}
+
+/// Computes `limbs * factor + addend`, or `None` if that overflows.
+fn mul_add_small<const N: usize>(limbs: &[u64; N], factor: u64, addend: u64) -> Option<[u64; N]> {
+    let mut out = [0; N];
+    let mut carry = addend;
+    for (limb, &x) in out.iter_mut().zip(limbs) {
+        let acc = u128::from(x) * u128::from(factor) + u128::from(carry);
+        *limb = acc as u64;
+        carry = (acc >> 64) as u64;
+    }
+    if carry == 0 {
+        Some(out)
+    } else {
+        None
+    }
+}
+
+/// Divides `limbs` by `divisor` in place and returns the remainder.
+fn div_rem_small<const N: usize>(limbs: &mut [u64; N], divisor: u64) -> u64 {
+    let mut rem = 0;
+    for limb in limbs.iter_mut().rev() {
+        let acc = (u128::from(rem) << 64) | u128::from(*limb);
+        *limb = (acc / u128::from(divisor)) as u64;
+        rem = (acc % u128::from(divisor)) as u64;
+    }
+    rem
+}
+
+macro_rules! wide_uint {
+    ($($(#[$attr:meta])* $name:ident($limbs:literal);)*) => {
+        $(
+            $(#[$attr])*
+            #[derive(PartialEq, Eq, Hash, Copy, Clone, Default)]
+            pub struct $name([u64; $limbs]);
+
+            impl $name {
+                pub const MIN: Self = Self([0; $limbs]);
+                pub const MAX: Self = Self([u64::MAX; $limbs]);
+
+                /// Creates a value from its 64-bit limbs, least significant
+                /// first.
+                pub const fn from_limbs(limbs: [u64; $limbs]) -> Self {
+                    Self(limbs)
+                }
+
+                /// Returns the 64-bit limbs, least significant first.
+                pub const fn to_limbs(self) -> [u64; $limbs] {
+                    self.0
+                }
+
+                /// Returns the wrapped sum and whether it overflowed.
+                pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                    let (limbs, overflowed) = overflowing_add(&self.0, &rhs.0);
+                    (Self(limbs), overflowed)
+                }
+
+                /// Returns the wrapped difference and whether it overflowed.
+                pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                    let (limbs, overflowed) = overflowing_sub(&self.0, &rhs.0);
+                    (Self(limbs), overflowed)
+                }
+
+                /// Returns the wrapped product and whether it overflowed.
+                pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                    let (limbs, overflowed) = overflowing_mul(&self.0, &rhs.0);
+                    (Self(limbs), overflowed)
+                }
+            }
+
+            impl Ord for $name {
+                fn cmp(&self, other: &Self) -> Ordering {
+                    self.0.iter().rev().cmp(other.0.iter().rev())
+                }
+            }
+
+            impl PartialOrd for $name {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            // Like the primitives in debug builds, the plain operators panic
+            // on overflow. Wrap the value in a `SaturatingNumber` to clamp.
+
+            impl Add for $name {
+                type Output = Self;
+
+                fn add(self, rhs: Self) -> Self {
+                    let (sum, overflowed) = self.overflowing_add(rhs);
+                    assert!(!overflowed, "attempt to add with overflow");
+                    sum
+                }
+            }
+
+            impl Sub for $name {
+                type Output = Self;
+
+                fn sub(self, rhs: Self) -> Self {
+                    let (diff, overflowed) = self.overflowing_sub(rhs);
+                    assert!(!overflowed, "attempt to subtract with overflow");
+                    diff
+                }
+            }
+
+            impl Mul for $name {
+                type Output = Self;
+
+                fn mul(self, rhs: Self) -> Self {
+                    let (product, overflowed) = self.overflowing_mul(rhs);
+                    assert!(!overflowed, "attempt to multiply with overflow");
+                    product
+                }
+            }
+
+            impl HasSaturatingAdd for $name {
+                fn do_saturating_add(&self, rhs: &Self) -> Self {
+                    self.do_saturating_add_reporting(rhs).0
+                }
+
+                fn do_saturating_add_reporting(&self, rhs: &Self) -> (Self, Saturation) {
+                    match self.overflowing_add(*rhs) {
+                        (sum, false) => (sum, Saturation::None),
+                        (_, true) => (Self::MAX, Saturation::Upper),
+                    }
+                }
+
+                fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(self, f)
+                }
+            }
+
+            impl HasSaturatingSub for $name {
+                fn do_saturating_sub(&self, rhs: &Self) -> Self {
+                    self.do_saturating_sub_reporting(rhs).0
+                }
+
+                fn do_saturating_sub_reporting(&self, rhs: &Self) -> (Self, Saturation) {
+                    match self.overflowing_sub(*rhs) {
+                        (diff, false) => (diff, Saturation::None),
+                        (_, true) => (Self::MIN, Saturation::Lower),
+                    }
+                }
+
+                fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(self, f)
+                }
+            }
+
+            impl HasSaturatingMul for $name {
+                fn do_saturating_mul(&self, rhs: &Self) -> Self {
+                    self.do_saturating_mul_reporting(rhs).0
+                }
+
+                fn do_saturating_mul_reporting(&self, rhs: &Self) -> (Self, Saturation) {
+                    match self.overflowing_mul(*rhs) {
+                        (product, false) => (product, Saturation::None),
+                        (_, true) => (Self::MAX, Saturation::Upper),
+                    }
+                }
+
+                fn fmt_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(self, f)
+                }
+            }
+
+            impl HasWrappingAdd for $name {
+                fn do_wrapping_add(&self, rhs: &Self) -> Self {
+                    self.overflowing_add(*rhs).0
+                }
+            }
+
+            impl HasWrappingSub for $name {
+                fn do_wrapping_sub(&self, rhs: &Self) -> Self {
+                    self.overflowing_sub(*rhs).0
+                }
+            }
+
+            impl HasWrappingMul for $name {
+                fn do_wrapping_mul(&self, rhs: &Self) -> Self {
+                    self.overflowing_mul(*rhs).0
+                }
+            }
+
+            impl HasSaturatingSum for $name {
+                fn zero() -> Self {
+                    Self::MIN
+                }
+
+                fn absorbs_add(&self) -> bool {
+                    *self == Self::MAX
+                }
+            }
+
+            impl HasSaturatingProduct for $name {
+                fn one() -> Self {
+                    Self::from(1u8)
+                }
+
+                fn absorbs_mul(&self) -> bool {
+                    *self == Self::MIN
+                }
+            }
+
+            impl HasSaturatingBounds for $name {
+                fn is_at_bound(&self) -> bool {
+                    *self == Self::MAX
+                }
+            }
+
+            impl HasSaturatingParse for $name {
+                fn do_parse(src: &str, saturate: bool) -> Result<Self, ParseSaturatingError> {
+                    let (negative, radix, rest) = split(src)?;
+                    let mut limbs = [0; $limbs];
+                    let mut overflowed = false;
+                    for digit in digits(rest, radix) {
+                        let digit = u64::from(digit?);
+                        if overflowed {
+                            continue;
+                        }
+                        // Any nonzero digit makes a negative number, which
+                        // lies below zero.
+                        let next = if negative {
+                            Some(limbs).filter(|_| digit == 0)
+                        } else {
+                            mul_add_small(&limbs, u64::from(radix), digit)
+                        };
+                        match next {
+                            Some(next) => limbs = next,
+                            None => overflowed = true,
+                        }
+                    }
+                    if !overflowed {
+                        Ok(Self(limbs))
+                    } else if saturate {
+                        Ok(if negative { Self::MIN } else { Self::MAX })
+                    } else if negative {
+                        Err(ParseErrorKind::NegOverflow.into())
+                    } else {
+                        Err(ParseErrorKind::PosOverflow.into())
+                    }
+                }
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    // Each limb contributes fewer than 20 decimal digits.
+                    let mut buf = [0u8; $limbs * 20];
+                    let mut start = buf.len();
+                    let mut limbs = self.0;
+                    loop {
+                        start -= 1;
+                        buf[start] = b'0' + div_rem_small(&mut limbs, 10) as u8;
+                        if limbs.iter().all(|&limb| limb == 0) {
+                            break;
+                        }
+                    }
+                    let digits = str::from_utf8(&buf[start..]).expect("digits are ASCII");
+                    f.pad_integral(true, "", digits)
+                }
+            }
+
+            impl fmt::Debug for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(self, f)
+                }
+            }
+
+            wide_uint!(@from $name($limbs): u8, u16, u32, u64, u128);
+        )*
+    };
+    (@from $name:ident($limbs:literal): $($t:ty),*) => {
+        $(
+            impl From<$t> for $name {
+                fn from(value: $t) -> Self {
+                    let value = u128::from(value);
+                    let mut limbs = [0; $limbs];
+                    limbs[0] = value as u64;
+                    limbs[1] = (value >> 64) as u64;
+                    Self(limbs)
+                }
+            }
+        )*
+    };
+}
+
+wide_uint! {
+    /// A 256-bit unsigned integer.
+    U256(4);
+    /// A 512-bit unsigned integer.
+    U512(8);
+}
+
+impl From<U256> for U512 {
+    fn from(value: U256) -> Self {
+        let mut limbs = [0; 8];
+        limbs[..4].copy_from_slice(&value.0);
+        Self(limbs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::XorShift;
+    use crate::{SaturatingNumber, SaturatingU256, SaturatingU512};
+
+    // The reference implementation stores 128-bit limbs, least significant
+    // first, and multiplies by shifting and adding one bit at a time.
+
+    fn ref_add<const N: usize>(a: [u128; N], b: [u128; N]) -> Option<[u128; N]> {
+        let mut out = [0; N];
+        let mut carry = 0;
+        for i in 0..N {
+            let (sum, c1) = a[i].overflowing_add(b[i]);
+            let (sum, c2) = sum.overflowing_add(carry);
+            out[i] = sum;
+            carry = u128::from(c1 || c2);
+        }
+        Some(out).filter(|_| carry == 0)
+    }
+
+    fn ref_sub<const N: usize>(a: [u128; N], b: [u128; N]) -> Option<[u128; N]> {
+        let mut out = [0; N];
+        let mut borrow = 0;
+        for i in 0..N {
+            let (diff, b1) = a[i].overflowing_sub(b[i]);
+            let (diff, b2) = diff.overflowing_sub(borrow);
+            out[i] = diff;
+            borrow = u128::from(b1 || b2);
+        }
+        Some(out).filter(|_| borrow == 0)
+    }
+
+    fn ref_mul<const N: usize>(a: [u128; N], b: [u128; N]) -> Option<[u128; N]> {
+        let mut acc = [0; N];
+        for bit in (0..N * 128).rev() {
+            acc = ref_add(acc, acc)?;
+            if b[bit / 128] >> (bit % 128) & 1 == 1 {
+                acc = ref_add(acc, a)?;
+            }
+        }
+        Some(acc)
+    }
+
+    fn ref_cmp<const N: usize>(a: [u128; N], b: [u128; N]) -> Ordering {
+        a.iter().rev().cmp(b.iter().rev())
+    }
+
+    fn ref_hex<const N: usize>(a: [u128; N]) -> String {
+        let mut hex = String::from("0x");
+        for limb in a.iter().rev() {
+            hex.push_str(&format!("{:032x}", limb));
+        }
+        hex
+    }
+
+    fn to_ref<const L: usize, const N: usize>(limbs: [u64; L]) -> [u128; N] {
+        let mut out = [0; N];
+        for (i, limb) in out.iter_mut().enumerate() {
+            *limb = u128::from(limbs[2 * i]) | u128::from(limbs[2 * i + 1]) << 64;
+        }
+        out
+    }
+
+    fn from_ref<const L: usize, const N: usize>(limbs: [u128; N]) -> [u64; L] {
+        let mut out = [0; L];
+        for (i, limb) in limbs.iter().enumerate() {
+            out[2 * i] = *limb as u64;
+            out[2 * i + 1] = (limb >> 64) as u64;
+        }
+        out
+    }
+
+    /// Limb patterns around every carry boundary, around the square root of
+    /// the maximum, and a few pseudo-random values.
+    fn test_values<const L: usize>() -> Vec<[u64; L]> {
+        let mut values = vec![[0; L], [u64::MAX; L]];
+        let mut max_minus_one = [u64::MAX; L];
+        max_minus_one[0] -= 1;
+        values.push(max_minus_one);
+        for i in 0..L {
+            for &limb in &[1, 2, 3, u64::MAX, u64::MAX - 1, 1 << 63] {
+                let mut single = [0; L];
+                single[i] = limb;
+                values.push(single);
+            }
+            // 2^(64 * (i + 1)) - 1, i.e. every limb up to `i` set.
+            let mut low_ones = [0; L];
+            low_ones[..=i].copy_from_slice(&[u64::MAX; L][..=i]);
+            values.push(low_ones);
+            let mut high_ones = [u64::MAX; L];
+            high_ones[..i].copy_from_slice(&[0; L][..i]);
+            values.push(high_ones);
+        }
+        let mut rng = XorShift::new(0x2545_f491_4f6c_dd1d);
+        for _ in 0..24 {
+            let mut random = [0; L];
+            for limb in random.iter_mut() {
+                *limb = rng.next_u64();
+            }
+            // Vary the magnitude so that products both fit and overflow.
+            let len = (rng.next_u64() % L as u64) as usize + 1;
+            random[len..].iter_mut().for_each(|limb| *limb = 0);
+            values.push(random);
+        }
+        values
+    }
+
+    macro_rules! check_against_reference {
+        ($t:ident, $saturating:ident, $limbs:literal, $ref_limbs:literal) => {{
+            let values = test_values::<$limbs>();
+            for &a in &values {
+                let ra: [u128; $ref_limbs] = to_ref(a);
+                let x = $saturating::from($t::from_limbs(a));
+                for &b in &values {
+                    let rb: [u128; $ref_limbs] = to_ref(b);
+                    let y = $saturating::from($t::from_limbs(b));
+                    let expected = |r: Option<[u128; $ref_limbs]>, bound: $t| {
+                        r.map_or(bound, |r| $t::from_limbs(from_ref(r)))
+                    };
+                    assert_eq!(x + y, $saturating::from(expected(ref_add(ra, rb), $t::MAX)));
+                    assert_eq!(x - y, $saturating::from(expected(ref_sub(ra, rb), $t::MIN)));
+                    assert_eq!(x * y, $saturating::from(expected(ref_mul(ra, rb), $t::MAX)));
+                    assert_eq!(x.cmp(&y), ref_cmp(ra, rb));
+                    let (_, saturation) = x.mul_reporting(y);
+                    assert_eq!(saturation == Saturation::None, ref_mul(ra, rb).is_some());
+                }
+                assert_eq!(ref_hex(ra).parse::<$saturating>(), Ok(x));
+                assert_eq!(x.to_string().parse::<$saturating>(), Ok(x));
+            }
+        }};
+    }
+
+    #[test]
+    fn test_u256_against_reference() {
+        check_against_reference!(U256, SaturatingU256, 4, 2);
+    }
+
+    #[test]
+    fn test_u512_against_reference() {
+        check_against_reference!(U512, SaturatingU512, 8, 4);
+    }
+
+    #[test]
+    fn test_carry_and_borrow_chains() {
+        let ones = U256::from_limbs([u64::MAX, u64::MAX, u64::MAX, 0]);
+        assert_eq!(
+            ones.overflowing_add(U256::from(1u8)),
+            (U256::from_limbs([0, 0, 0, 1]), false)
+        );
+        assert_eq!(
+            U256::from_limbs([0, 0, 0, 1]).overflowing_sub(U256::from(1u8)),
+            (ones, false)
+        );
+        assert_eq!(
+            U256::MAX.overflowing_add(U256::from(1u8)),
+            (U256::MIN, true)
+        );
+        assert_eq!(
+            U256::MIN.overflowing_sub(U256::from(1u8)),
+            (U256::MAX, true)
+        );
+        // (2^128)^2 is one past the maximum, while (2^128 - 1)^2 fits.
+        let root = U256::from_limbs([0, 0, 1, 0]);
+        assert_eq!(root.overflowing_mul(root), (U256::MIN, true));
+        let below = U256::from(u128::MAX);
+        assert_eq!(
+            below * below,
+            U256::from_limbs([1, 0, u64::MAX - 1, u64::MAX])
+        );
+    }
+
+    #[test]
+    fn test_saturation() {
+        let max = SaturatingU256::from(U256::MAX);
+        let one = SaturatingU256::from(U256::from(1u8));
+        assert_eq!(max + one, max);
+        assert_eq!(one - max, SaturatingU256::from(U256::MIN));
+        assert_eq!(max * max, max);
+        assert_eq!(max.add_reporting(one), (max, Saturation::Upper));
+        assert_eq!(
+            one.sub_reporting(max),
+            (SaturatingU256::from(U256::MIN), Saturation::Lower)
+        );
+        assert_eq!(vec![max, one, one].into_iter().sum::<SaturatingU256>(), max);
+        let wide = SaturatingU512::from(U512::from(U256::MAX));
+        assert!(wide * wide < SaturatingU512::from(U512::MAX));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_plain_operators_panic() {
+        let _ = U256::MAX + U256::from(1u8);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(U256::MIN.to_string(), "0");
+        assert_eq!(U256::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(
+            U256::MAX.to_string(),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assert_eq!(
+            U512::MAX.to_string(),
+            "13407807929942597099574024998205846127479365820592393377723561443721764030073546976\
+             801874298166903427690031858186486050853753882811946569946433649006084095"
+        );
+        assert_eq!(format!("{:>5}", U256::from(42u8)), "   42");
+        assert_eq!(format!("{:05?}", U512::from(42u8)), "00042");
+        assert_eq!(
+            format!("{:#}", SaturatingU256::from(U256::MAX)),
+            format!("{} (saturated)", U256::MAX)
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "-0".parse::<SaturatingU256>(),
+            Ok(SaturatingU256::from(U256::MIN))
+        );
+        assert_eq!(
+            "-1".parse::<SaturatingU256>(),
+            Ok(SaturatingU256::from(U256::MIN))
+        );
+        let too_big = format!("{}0", U256::MAX);
+        assert_eq!(
+            too_big.parse::<SaturatingU256>(),
+            Ok(SaturatingU256::from(U256::MAX))
+        );
+        assert_eq!(
+            SaturatingU256::parse_strict(&too_big).unwrap_err().kind(),
+            ParseErrorKind::PosOverflow
+        );
+        assert_eq!(
+            SaturatingNumber::<U512>::parse_strict("-1")
+                .unwrap_err()
+                .kind(),
+            ParseErrorKind::NegOverflow
+        );
+        assert_eq!(
+            "1_000x".parse::<SaturatingU512>().unwrap_err().kind(),
+            ParseErrorKind::InvalidDigit
+        );
+        assert_eq!(
+            "0b1_0000_0000".parse::<SaturatingU512>(),
+            Ok(SaturatingU512::from(U512::from(256u16)))
+        );
+    }
+}
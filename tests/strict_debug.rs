@@ -0,0 +1,226 @@
+//! The `strict-debug` checks are compiled out of the crate's own unit tests,
+//! so they are exercised from here.
+
+#![cfg(all(feature = "strict-debug", debug_assertions))]
+
+#[cfg(feature = "std")]
+use saturating_numbers::ShardedSaturatingCounter;
+use saturating_numbers::{
+    BoundedU8, Capped, DivByZero, HasSaturatingAdd, Number, SaturatingI8, SaturatingNumber,
+    SaturatingU24, SaturatingU256, SaturatingU64, SaturatingU8, Saturation, Tracked, Uint, Wrap,
+    U256,
+};
+use std::{num::Saturating, panic};
+
+fn panic_message(f: impl FnOnce() + panic::UnwindSafe) -> String {
+    let payload = panic::catch_unwind(f).expect_err("expected a panic");
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().unwrap().to_string(),
+    }
+}
+
+#[test]
+fn test_exact_results_do_not_panic() {
+    let mut x = SaturatingU8::from(250);
+    x += 5;
+    x -= SaturatingU8::from(255);
+    assert_eq!(x * 7u8, SaturatingU8::from(0));
+    assert_eq!(3u8 * SaturatingU8::from(85), SaturatingU8::from(255));
+}
+
+#[test]
+fn test_clamping_operators_panic() {
+    assert_eq!(
+        panic_message(|| {
+            let _ = SaturatingU8::from(250) + SaturatingU8::from(10);
+        }),
+        "`+` of 250 and 10 saturated at the upper bound (strict-debug)"
+    );
+    assert_eq!(
+        panic_message(|| {
+            let mut x = SaturatingI8::from(-100);
+            x -= &SaturatingI8::from(100);
+        }),
+        "`-` of -100 and 100 saturated at the lower bound (strict-debug)"
+    );
+    assert_eq!(
+        panic_message(|| {
+            let _ = 2u64 * SaturatingU64::from(u64::MAX);
+        }),
+        "`*` of 2 and 18446744073709551615 saturated at the upper bound (strict-debug)"
+    );
+    assert_eq!(
+        panic_message(|| {
+            let _ = SaturatingU8::from(1) - Saturating(2u8);
+        }),
+        "`-` of 1 and 2 saturated at the lower bound (strict-debug)"
+    );
+}
+
+#[test]
+fn test_other_clamping_operators_panic() {
+    assert_eq!(
+        panic_message(|| {
+            let _ = -SaturatingI8::from(i8::MIN);
+        }),
+        "`-` of -128 saturated at the upper bound (strict-debug)"
+    );
+    assert_eq!(
+        panic_message(|| {
+            let _ = SaturatingU8::from(200) << 3;
+        }),
+        "`<<` of 200 and 3 saturated at the upper bound (strict-debug)"
+    );
+    assert_eq!(
+        panic_message(|| {
+            let _ = SaturatingI8::from(i8::MIN) / SaturatingI8::from(-1);
+        }),
+        "`/` of -128 and -1 saturated at the upper bound (strict-debug)"
+    );
+    assert_eq!(
+        panic_message(|| {
+            let _ = SaturatingU8::from(16).pow(2);
+        }),
+        "`pow` of 16 and 2 saturated at the upper bound (strict-debug)"
+    );
+    assert_eq!(
+        panic_message(|| {
+            let _: SaturatingU8 = [200, 100].iter().map(|&x| SaturatingU8::from(x)).sum();
+        }),
+        "`+` of 200 and 100 saturated at the upper bound (strict-debug)"
+    );
+}
+
+#[test]
+fn test_sums_check_every_item() {
+    for (items, message) in [
+        (
+            [u8::MAX, 1],
+            "`+` of 255 and 1 saturated at the upper bound (strict-debug)",
+        ),
+        (
+            [1, u8::MAX],
+            "`+` of 1 and 255 saturated at the upper bound (strict-debug)",
+        ),
+    ] {
+        assert_eq!(
+            panic_message(move || {
+                let _: SaturatingU8 = items.iter().map(|&x| SaturatingU8::from(x)).sum();
+            }),
+            message
+        );
+    }
+    assert_eq!(
+        panic_message(|| {
+            let _: SaturatingU8 = [u8::MAX, 2]
+                .iter()
+                .map(|&x| SaturatingU8::from(x))
+                .product();
+        }),
+        "`*` of 255 and 2 saturated at the upper bound (strict-debug)"
+    );
+}
+
+#[test]
+fn test_wide_operands_are_shown() {
+    assert_eq!(
+        panic_message(|| {
+            let _ = SaturatingU256::from(U256::MAX) * SaturatingU256::from(U256::from(2u8));
+        }),
+        "`*` of 115792089237316195423570985008687907853269984665640564039457584007913129639935 \
+         and 2 saturated at the upper bound (strict-debug)"
+    );
+    assert_eq!(
+        panic_message(|| {
+            let _ = SaturatingU24::from(Uint::MIN) - SaturatingU24::from(Uint::clamped(1));
+        }),
+        "`-` of 0 and 1 saturated at the lower bound (strict-debug)"
+    );
+}
+
+#[test]
+fn test_explicit_saturation_is_still_allowed() {
+    let max = SaturatingU8::from(u8::MAX);
+    assert_eq!(max.add_reporting(max), (max, Saturation::Upper));
+    assert!((Tracked::new(max) + Tracked::new(max)).was_saturated());
+    assert_eq!(
+        *(Capped::with_cap(200u8, 250) + Capped::with_cap(100, 250)).get(),
+        250
+    );
+    type Percentage = BoundedU8<0, 100>;
+    assert_eq!(
+        Percentage::clamped(20) * Percentage::clamped(20),
+        Percentage::MAX
+    );
+    assert_eq!(Number::<u8, Wrap>::from(u8::MAX) + 1, 0);
+}
+
+#[test]
+fn test_explicit_zero_divisor_policy_is_allowed() {
+    let zero = SaturatingI8::from(0);
+    assert_eq!(
+        SaturatingI8::from(5).div_with(zero, DivByZero::Saturate),
+        SaturatingI8::from(i8::MAX)
+    );
+    assert_eq!(
+        SaturatingI8::from(-5).div_with(zero, DivByZero::Saturate),
+        SaturatingI8::from(i8::MIN)
+    );
+    assert_eq!(
+        panic_message(|| {
+            let _ =
+                SaturatingI8::from(i8::MIN).div_with(SaturatingI8::from(-1), DivByZero::Saturate);
+        }),
+        "`/` of -128 and -1 saturated at the upper bound (strict-debug)"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_sharded_counter_reads_max() {
+    // Two threads fill two shards, so reading the counter adds two maxima.
+    let counter = ShardedSaturatingCounter::with_shards(2);
+    std::thread::scope(|scope| {
+        for _ in 0..2 {
+            scope.spawn(|| counter.add(SaturatingU64::from(u64::MAX)));
+        }
+    });
+    assert_eq!(counter.sum(), SaturatingU64::from(u64::MAX));
+}
+
+/// An inner type without `Debug` or `Display`, which the feature must not
+/// require. Its operands show as `_`.
+#[derive(PartialEq)]
+struct Opaque(u8);
+
+impl std::ops::Add for Opaque {
+    type Output = Opaque;
+
+    fn add(self, rhs: Opaque) -> Opaque {
+        Opaque(self.0 + rhs.0)
+    }
+}
+
+impl HasSaturatingAdd for Opaque {
+    fn do_saturating_add(&self, rhs: &Self) -> Self {
+        Opaque(self.0.saturating_add(rhs.0))
+    }
+
+    fn do_saturating_add_reporting(&self, rhs: &Self) -> (Self, Saturation) {
+        let (sum, saturation) = self.0.do_saturating_add_reporting(&rhs.0);
+        (Opaque(sum), saturation)
+    }
+}
+
+#[test]
+fn test_inner_types_need_not_implement_debug() {
+    let sum = SaturatingNumber::from(Opaque(1)) + SaturatingNumber::from(Opaque(2));
+    assert!(sum == Opaque(3));
+    assert_eq!(
+        panic_message(|| {
+            let _ = SaturatingNumber::from(Opaque(200)) + SaturatingNumber::from(Opaque(100));
+        }),
+        "`+` of _ and _ saturated at the upper bound (strict-debug)"
+    );
+}